@@ -0,0 +1,27 @@
+use sanitizeable::sanitizeable;
+
+// This file should not compile
+
+// The getters would collide with the methods added by the other extensions
+#[sanitizeable(accessors, erase, sign, seal, view_sql)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[public_attr::derive(Clone)]
+struct Account {
+    pub erase_private: u64,
+    pub sign_public: u64,
+    pub seal: u64,
+    pub create_public_view_sql: u64,
+    pub public_mut: u64,
+    #[private]
+    pub secret: u64,
+}
+
+// The GraphQL resolvers are named like the fields
+#[sanitizeable(accessors, graphql)]
+struct User {
+    pub name: String,
+    #[private]
+    pub password: String,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use sanitizeable::{sanitizeable, Sanitizeable};
+
+// This file should not compile
+
+struct Noisy;
+
+impl Drop for Noisy {
+    fn drop(&mut self) {}
+}
+
+#[sanitizeable(copy)]
+struct Pair<T> {
+    pub left: T,
+    #[private]
+    pub right: T,
+}
+
+fn main() {
+    // The container never drops its fields, so it can only hold `Copy` types
+    let _ = Pair::from_private(PairPrivate {
+        left: Noisy,
+        right: Noisy,
+    });
+}
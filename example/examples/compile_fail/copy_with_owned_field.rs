@@ -0,0 +1,13 @@
+use sanitizeable::sanitizeable;
+
+// This file should not compile
+
+#[sanitizeable(copy)]
+struct Account {
+    id: u64,
+    #[private]
+    // Copying the container would duplicate this allocation without a `Drop` to free it
+    password: String,
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use sanitizeable::sanitizeable;
+
+// This file should not compile
+
+mod schema {
+    diesel::table! {
+        users (id) {
+            id -> Integer,
+            #[sql_name = "display-name"]
+            display_name -> Text,
+            password_hash -> Text,
+        }
+    }
+}
+
+// `table!` names its columns with Rust identifiers, not with their SQL names
+#[sanitizeable(diesel_table = "schema::users")]
+struct User {
+    pub id: i32,
+    #[column = "display-name"]
+    pub display_name: String,
+    #[private]
+    #[column = "self"]
+    pub password_hash: String,
+}
+
+fn main() {}
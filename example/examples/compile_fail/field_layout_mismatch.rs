@@ -0,0 +1,32 @@
+use sanitizeable::sanitizeable;
+
+// This file should not compile
+
+trait Pick {
+    type Out;
+}
+
+impl Pick for WidePublic {
+    type Out = u64;
+}
+
+impl Pick for WidePrivate {
+    type Out = u8;
+}
+
+macro_rules! hide {
+    ($ty:ty) => {
+        $ty
+    };
+}
+
+// `value` has the same offset in both variants and the public variant is not larger,
+// but reading `value` from the public variant would also read the padding after it
+#[sanitizeable]
+struct Wide {
+    pub value: <hide!(Self) as Pick>::Out,
+    #[private]
+    secret: u64,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use sanitizeable::{sanitizeable, Sanitizeable};
+
+// This file should not compile, but stand-ins for the types are still emitted so using them is not an error
+
+#[sanitizeable(public_name = "View", public_name = "Other")]
+#[public_attr]
+#[derive(Debug)]
+struct User {
+    #[private_attr]
+    name: String,
+    #[private = "yes"]
+    password: String,
+}
+
+fn main() {
+    let user = User::from_private(UserPrivate {
+        name: "soruh".to_string(),
+        password: "hunter2".to_string(),
+    });
+    println!("{:?}", user.private());
+}
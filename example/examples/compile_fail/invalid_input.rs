@@ -0,0 +1,16 @@
+use sanitizeable::sanitizeable;
+
+// This file should not compile, and every mistake should be reported at once
+
+#[sanitizeable(privat_name = "Secret", public_name = 5, container_name = "not an identifier", seal = "yes")]
+#[publik_attr::derive(Debug)]
+struct User {
+    #[public_attr::derive(Clone)]
+    name: String,
+    #[private(erase_wth = "clear")]
+    password: String,
+    #[private(erase_with = "String::new")]
+    token: String,
+}
+
+fn main() {}
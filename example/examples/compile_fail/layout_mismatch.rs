@@ -0,0 +1,32 @@
+use sanitizeable::sanitizeable;
+
+// This file should not compile
+
+trait Pick {
+    type Out;
+}
+
+impl Pick for MismatchPublic {
+    type Out = u64;
+}
+
+impl Pick for MismatchPrivate {
+    type Out = u8;
+}
+
+macro_rules! hide {
+    ($ty:ty) => {
+        $ty
+    };
+}
+
+// `Self` is hidden from the macro, so the field has a different type in each variant
+// and the layout assertions fail, even though `public` is never called
+#[sanitizeable]
+struct Mismatch {
+    pub value: <hide!(Self) as Pick>::Out,
+    #[private]
+    secret: u8,
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use sanitizeable::sanitizeable;
+
+// This file should not compile
+
+#[sanitizeable(accessors)]
+struct User {
+    pub name: String,
+    #[private]
+    pub password: String,
+}
+
+fn main() {
+    let user = User::new_for_test();
+    // Only public fields get a getter
+    let _ = user.password();
+}
+
+impl User {
+    fn new_for_test() -> Self {
+        sanitizeable::Sanitizeable::from_private(UserPrivate {
+            name: "alice".to_string(),
+            password: "hunter2".to_string(),
+        })
+    }
+}
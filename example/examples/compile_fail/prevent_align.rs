@@ -0,0 +1,21 @@
+use sanitizeable::{sanitizeable, Sanitizeable};
+
+// This file should not compile
+
+#[sanitizeable]
+#[public_attr::repr(align(64))]
+// The public variant could no longer be read from the storage of the private variant
+struct OverAligned {
+    value: u64,
+    #[private]
+    secret: u32,
+}
+
+fn main() {
+    let aligned = OverAligned::from_private(OverAlignedPrivate {
+        value: 1,
+        secret: 2,
+    });
+
+    assert_eq!(aligned.public().value, 1);
+}
@@ -0,0 +1,21 @@
+use sanitizeable::{sanitizeable, Sanitizeable};
+
+// This file should not compile
+
+#[sanitizeable]
+struct Conditional {
+    #[public_attr::cfg_attr(not(test), cfg(any()))]
+    // This would remove the field from only the public variant
+    value: u64,
+    #[private]
+    secret: u32,
+}
+
+fn main() {
+    let conditional = Conditional::from_private(ConditionalPrivate {
+        value: 1,
+        secret: 2,
+    });
+
+    assert_eq!(conditional.public().value, 1);
+}
@@ -0,0 +1,23 @@
+use sanitizeable::{sanitizeable, Sanitizeable};
+
+// This file should not compile
+
+#[sanitizeable]
+#[private_attr::repr(packed)]
+// The public fields would no longer be at the same offsets in both variants
+struct Misaligned {
+    flag: u8,
+    value: u64,
+    #[private]
+    secret: u32,
+}
+
+fn main() {
+    let misaligned = Misaligned::from_private(MisalignedPrivate {
+        flag: 1,
+        value: 2,
+        secret: 3,
+    });
+
+    assert_eq!(misaligned.public().value, 2);
+}
@@ -0,0 +1,37 @@
+use sanitizeable::{sanitizeable, Sanitizeable};
+
+// This file should not compile
+
+// A public field holding containers would make the private fields of the replies reachable from the public variant
+#[sanitizeable(container_name = "Comment")]
+struct CommentData {
+    pub text: String,
+    #[private]
+    pub author_ip: String,
+    pub replies: Vec<Self>,
+    pub parent: Option<Box<CommentData>>,
+    pub siblings: Vec<Comment>,
+}
+
+trait Tree {
+    type Node;
+}
+
+#[sanitizeable]
+struct Node {
+    pub value: u64,
+    #[private]
+    pub children: Self::Node,
+}
+
+fn main() {
+    let thread = Comment::from_private(CommentDataPrivate {
+        text: "first".into(),
+        author_ip: "127.0.0.1".into(),
+        replies: vec![],
+        parent: None,
+        siblings: vec![],
+    });
+
+    let _ = &thread.public().replies[0].private().author_ip;
+}
@@ -0,0 +1,13 @@
+use sanitizeable::sanitizeable;
+
+// This file should not compile
+
+#[sanitizeable(seal)]
+struct Message {
+    // The sealed struct already has a `ciphertext` field
+    pub ciphertext: Vec<u8>,
+    #[private]
+    pub body: String,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use sanitizeable::sanitizeable;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+// Equal hash maps and sets can serialize to different bytes, so their signatures could fail to verify
+
+#[sanitizeable(sign)]
+#[public_attr::derive(Clone, serde::Serialize)]
+struct Catalogue {
+    pub prices: HashMap<String, u64>,
+    pub tags: Vec<HashSet<String>>,
+    pub discounts: BTreeMap<String, u64>,
+    #[private]
+    pub suppliers: HashMap<String, String>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use sanitizeable::sanitizeable;
+
+// This file should not compile
+
+// The columns of a view need names
+#[sanitizeable(view_sql)]
+struct Point(pub i64, #[private] pub i64);
+
+fn main() {}
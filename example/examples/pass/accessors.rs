@@ -0,0 +1,28 @@
+mod users {
+    use sanitizeable::sanitizeable;
+
+    #[sanitizeable(accessors)]
+    pub struct User {
+        pub name: String,
+        pub age: u8,
+        #[private]
+        pub password: String,
+    }
+}
+
+fn main() {
+    // `Sanitizeable` is not in scope here, so these are the inherent methods
+    let user = <users::User as sanitizeable::Sanitizeable>::from_private(users::UserPrivate {
+        name: "alice".to_string(),
+        age: 30,
+        password: "hunter2".to_string(),
+    });
+
+    assert_eq!(user.name(), "alice");
+    assert_eq!(*user.age(), 30);
+    assert_eq!(user.public().name, "alice");
+    assert_eq!(user.private().password, "hunter2");
+
+    let private = user.into_private();
+    assert_eq!(private.password, "hunter2");
+}
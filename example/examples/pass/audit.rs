@@ -0,0 +1,40 @@
+use sanitizeable::{sanitizeable, Sanitizeable};
+
+// `tests/audit.rs` builds this with `SANITIZEABLE_AUDIT_DIR` set and checks the resulting manifest
+
+#[sanitizeable(public_name = "AccountView")]
+#[derive(Debug)]
+#[public_attr::derive(Clone)]
+struct Account {
+    /// Public handle of the account
+    pub handle: String,
+    #[private_attr::doc = "Only ever compared against hashes"]
+    pub password_hash: String,
+    #[private]
+    #[classify(PII, retention = "90d")]
+    pub email: String,
+}
+
+fn main() {
+    let account = Account::from_private(AccountPrivate {
+        handle: "maxi".into(),
+        password_hash: "$argon2id$...".into(),
+        email: "max@example.com".into(),
+    });
+
+    dbg!(account.public());
+    dbg!(legacy::Account::from_private(legacy::AccountPrivate { id: 1, pin: 1234 }).public());
+}
+
+// A second struct with the same name in the same file gets its own manifest entry
+mod legacy {
+    use sanitizeable::sanitizeable;
+
+    #[sanitizeable]
+    #[derive(Debug)]
+    pub struct Account {
+        pub id: u64,
+        #[private]
+        pub pin: u16,
+    }
+}
@@ -0,0 +1,67 @@
+use sanitizeable::sanitizeable;
+use std::{cell::Cell, marker::PhantomPinned, rc::Rc};
+
+// Checks whether a type implements an auto trait without failing to compile if it does not:
+// the inherent constant only exists if the bound holds and is preferred over the trait constant
+
+struct Check<T: ?Sized>(std::marker::PhantomData<T>);
+
+trait Fallback {
+    const SEND: bool = false;
+    const SYNC: bool = false;
+    const UNPIN: bool = false;
+}
+impl<T: ?Sized> Fallback for Check<T> {}
+
+#[allow(dead_code)]
+impl<T: ?Sized + Send> Check<T> {
+    const SEND: bool = true;
+}
+#[allow(dead_code)]
+impl<T: ?Sized + Sync> Check<T> {
+    const SYNC: bool = true;
+}
+#[allow(dead_code)]
+impl<T: ?Sized + Unpin> Check<T> {
+    const UNPIN: bool = true;
+}
+
+macro_rules! auto_traits {
+    ($ty: ty) => {
+        (<Check<$ty>>::SEND, <Check<$ty>>::SYNC, <Check<$ty>>::UNPIN)
+    };
+}
+
+// By default, the placeholders have the auto traits of the private fields
+
+#[sanitizeable]
+struct Shared {
+    pub id: u64,
+    #[private]
+    pub handle: Rc<u64>,
+    #[private]
+    pub pinned: PhantomPinned,
+}
+
+// `fn() -> _` keeps the variance of the private fields, but implements all auto traits
+
+#[sanitizeable(phantom = "fn() -> _")]
+struct Detached {
+    pub id: u64,
+    #[private]
+    pub handle: Rc<u64>,
+    #[private]
+    pub counter: Cell<u64>,
+    #[private]
+    pub pinned: PhantomPinned,
+}
+
+fn main() {
+    assert_eq!(auto_traits!(SharedPublic), (false, false, false));
+    assert_eq!(auto_traits!(SharedPrivate), (false, false, false));
+    assert_eq!(auto_traits!(Shared), (false, false, false));
+
+    assert_eq!(auto_traits!(DetachedPublic), (true, true, true));
+    assert_eq!(auto_traits!(DetachedPrivate), (false, false, false));
+    assert_eq!(auto_traits!(Detached), (false, false, false));
+}
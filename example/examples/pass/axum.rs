@@ -0,0 +1,69 @@
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, Request, StatusCode},
+    routing::{get, post},
+    Router,
+};
+use sanitizeable::{axum::Json, sanitizeable, Sanitizeable};
+use tower::ServiceExt;
+
+#[sanitizeable(axum)]
+#[derive(Debug)]
+#[public_attr::derive(serde::Serialize)]
+#[private_attr::derive(serde::Deserialize)]
+struct User {
+    pub name: String,
+    pub karma: i64,
+    #[private]
+    pub password: String,
+}
+
+async fn show_user() -> User {
+    User::from_private(UserPrivate {
+        name: "soruh".to_string(),
+        karma: 5,
+        password: "hunter2".to_string(),
+    })
+}
+
+async fn register(Json(mut user): Json<User>) -> Json<User> {
+    assert_eq!(user.private().password, "correct horse battery staple");
+    user.public_mut().karma = 1;
+    Json(user)
+}
+
+async fn call(app: &Router, request: Request<Body>) -> (StatusCode, String) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+fn post_json(body: &str) -> Request<Body> {
+    Request::post("/users")
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let app = Router::new()
+        .route("/user", get(show_user))
+        .route("/users", post(register));
+
+    let (status, body) = call(&app, Request::get("/user").body(Body::empty()).unwrap()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, r#"{"name":"soruh","karma":5}"#);
+
+    let (status, body) = call(
+        &app,
+        post_json(r#"{"name":"new","karma":100,"password":"correct horse battery staple"}"#),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, r#"{"name":"new","karma":1}"#);
+
+    let (status, _) = call(&app, post_json(r#"{"name":"new","karma":100}"#)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
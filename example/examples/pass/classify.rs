@@ -0,0 +1,60 @@
+use sanitizeable::{sanitizeable, FieldInfo, Privacy};
+
+#[sanitizeable(catalogue)]
+#[derive(Debug)]
+struct Payment {
+    pub id: u64,
+    #[classify(PII, retention = "30d")]
+    pub customer_name: String,
+    #[private]
+    #[classify(PCI)]
+    #[classify(retention = "1y")]
+    pub card_number: String,
+    #[private]
+    #[classify(PCI, PII)]
+    pub billing_address: Option<String>,
+}
+
+fn main() {
+    assert_eq!(
+        Payment::FIELDS,
+        [
+            FieldInfo {
+                name: "id",
+                type_name: "u64",
+                privacy: Privacy::Public,
+                labels: &[],
+                retention: None,
+            },
+            FieldInfo {
+                name: "customer_name",
+                type_name: "String",
+                privacy: Privacy::Public,
+                labels: &["PII"],
+                retention: Some("30d"),
+            },
+            FieldInfo {
+                name: "card_number",
+                type_name: "String",
+                privacy: Privacy::Private,
+                labels: &["PCI"],
+                retention: Some("1y"),
+            },
+            FieldInfo {
+                name: "billing_address",
+                type_name: "Option<String>",
+                privacy: Privacy::Private,
+                labels: &["PCI", "PII"],
+                retention: None,
+            },
+        ]
+    );
+
+    let pci_fields = Payment::FIELDS
+        .iter()
+        .filter(|field| field.labels.contains(&"PCI"))
+        .map(|field| field.name)
+        .collect::<Vec<_>>();
+
+    assert_eq!(pci_fields, ["card_number", "billing_address"]);
+}
@@ -0,0 +1,33 @@
+use sanitizeable::{sanitizeable, Sanitizeable};
+
+#[sanitizeable(const_fn)]
+#[derive(Debug)]
+struct Account {
+    pub name: &'static str,
+    pub id: u32,
+    #[private]
+    pub api_key: &'static str,
+}
+
+static ACCOUNTS: [Account; 2] = [
+    Account::new(AccountPrivate {
+        name: "admin",
+        id: 1,
+        api_key: "secret",
+    }),
+    Account::new(AccountPrivate {
+        name: "guest",
+        id: 2,
+        api_key: "hunter2",
+    }),
+];
+
+const ADMIN_ID: u32 = ACCOUNTS[0].public().id;
+const GUEST_KEY: &str = ACCOUNTS[1].private().api_key;
+
+fn main() {
+    assert_eq!(ADMIN_ID, 1);
+    assert_eq!(GUEST_KEY, "hunter2");
+    assert_eq!(ACCOUNTS[1].public().name, "guest");
+    assert_eq!(Sanitizeable::public(&ACCOUNTS[1]).id, 2);
+}
@@ -0,0 +1,37 @@
+use sanitizeable::{sanitizeable, Sanitizeable};
+
+#[sanitizeable(copy)]
+#[derive(Debug, PartialEq)]
+struct Reading<T> {
+    pub sensor: u16,
+    pub value: T,
+    #[private]
+    pub calibration: f32,
+}
+
+fn main() {
+    let reading = Reading::from_private(ReadingPrivate {
+        sensor: 7,
+        value: 21.5,
+        calibration: 0.25,
+    });
+
+    let mut copy = reading;
+    copy.public_mut().value = 22.0;
+
+    assert_eq!(reading.public().value, 21.5);
+    assert_eq!(copy.public().value, 22.0);
+    assert_eq!(
+        *copy.public(),
+        ReadingPublic {
+            sensor: 7,
+            value: 22.0,
+            _calibration: std::marker::PhantomData,
+        }
+    );
+
+    let public = *reading.public();
+    let private = copy.into_private();
+    assert_eq!(public.sensor, private.sensor);
+    assert_eq!(private.calibration, 0.25);
+}
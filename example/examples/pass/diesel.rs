@@ -0,0 +1,119 @@
+use diesel::{debug_query, prelude::*, sqlite::Sqlite};
+use sanitizeable::{sanitizeable, Sanitizeable};
+
+mod schema {
+    diesel::table! {
+        users (id) {
+            id -> Integer,
+            name -> Text,
+            password_hash -> Text,
+            karma -> Integer,
+        }
+    }
+
+    diesel::table! {
+        accounts (id) {
+            id -> Integer,
+            email -> Text,
+            display_name -> Text,
+        }
+    }
+}
+
+use schema::{accounts, users};
+
+#[sanitizeable(diesel_table = "schema::users")]
+#[derive(Debug)]
+struct User {
+    pub id: i32,
+    pub name: String,
+    #[private]
+    pub password_hash: String,
+    pub karma: i32,
+}
+
+// The private column sits between two public columns of the same type
+#[sanitizeable(diesel_table = "schema::accounts")]
+#[derive(Debug)]
+struct Account {
+    pub id: i32,
+    #[private]
+    pub email: String,
+    pub display_name: String,
+}
+
+fn main() {
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query(
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL, password_hash TEXT NOT NULL, karma INTEGER NOT NULL)",
+    )
+    .execute(&mut connection)
+    .unwrap();
+
+    let user = User::from_private(UserPrivate {
+        id: 1,
+        name: "soruh".to_string(),
+        password_hash: "hunter2".to_string(),
+        karma: 5,
+    });
+    diesel::insert_into(users::table)
+        .values(&user)
+        .execute(&mut connection)
+        .unwrap();
+
+    let loaded: User = users::table
+        .select(User::as_select())
+        .first(&mut connection)
+        .unwrap();
+    assert_eq!(loaded.private().password_hash, "hunter2");
+    assert_eq!(loaded.public().name, "soruh");
+
+    let query = users::table.select(UserPublic::as_select());
+    let sql = debug_query::<Sqlite, _>(&query).to_string();
+    println!("{}", sql);
+    assert!(sql.contains("`karma`"));
+    assert!(!sql.contains("password_hash"));
+
+    let public: UserPublic = query.first(&mut connection).unwrap();
+    assert_eq!(public.id, 1);
+    assert_eq!(public.name, "soruh");
+    assert_eq!(public.karma, 5);
+
+    let private: Vec<UserPrivate> = users::table
+        .select(UserPrivate::as_select())
+        .load(&mut connection)
+        .unwrap();
+    assert_eq!(private[0].password_hash, "hunter2");
+
+    diesel::sql_query(
+        "CREATE TABLE accounts (id INTEGER PRIMARY KEY, email TEXT NOT NULL, display_name TEXT NOT NULL)",
+    )
+    .execute(&mut connection)
+    .unwrap();
+
+    let account = Account::from_private(AccountPrivate {
+        id: 1,
+        email: "secret@example.com".to_string(),
+        display_name: "soruh".to_string(),
+    });
+    diesel::insert_into(accounts::table)
+        .values(&account)
+        .execute(&mut connection)
+        .unwrap();
+
+    // Loading the whole table reads the columns in the order of `table!`
+    let loaded: Account = accounts::table.first(&mut connection).unwrap();
+    assert_eq!(loaded.public().display_name, "soruh");
+    assert_eq!(loaded.private().email, "secret@example.com");
+
+    let loaded: Vec<AccountPrivate> = accounts::table.load(&mut connection).unwrap();
+    assert_eq!(loaded[0].display_name, "soruh");
+    assert_eq!(loaded[0].email, "secret@example.com");
+
+    let loaded: Account = accounts::table
+        .select(Account::as_select())
+        .first(&mut connection)
+        .unwrap();
+    assert_eq!(loaded.public().display_name, "soruh");
+    assert_eq!(loaded.private().email, "secret@example.com");
+}
@@ -0,0 +1,84 @@
+use sanitizeable::{
+    encryption::{ChaCha20Poly1305Cipher, SealError},
+    sanitizeable, Sanitizeable,
+};
+
+#[sanitizeable(seal)]
+#[derive(Debug)]
+#[sealed_attr::derive(Clone)] // This is only applied to `PatientSealed`
+struct Patient {
+    pub id: u64,
+    pub ward: String,
+    #[private]
+    pub name: String,
+    #[private]
+    pub diagnosis: Vec<String>,
+    #[private]
+    pub r#type: char,
+}
+
+// Sealing also works for tuple structs
+#[sanitizeable(seal)]
+#[derive(Debug)]
+struct Pin(u32, #[private] u16);
+
+fn main() -> Result<(), SealError> {
+    let cipher = ChaCha20Poly1305Cipher::new(&ChaCha20Poly1305Cipher::generate_key());
+
+    let patient = Patient::from_private(PatientPrivate {
+        id: 7,
+        ward: "Cardiology".into(),
+        name: "Erika Mustermann".into(),
+        diagnosis: vec!["Arrhythmia".into()],
+        r#type: 'A',
+    });
+
+    let sealed: PatientSealed = patient.seal(&cipher)?;
+
+    // public fields stay queryable
+    assert_eq!(sealed.id, 7);
+    assert_eq!(sealed.ward, "Cardiology");
+
+    // private fields are not stored in the clear
+    let needle = b"Mustermann";
+    assert!(!sealed
+        .ciphertext
+        .windows(needle.len())
+        .any(|window| window == needle));
+
+    let unsealed = sealed.clone().unseal(&cipher)?;
+    assert_eq!(unsealed.private().name, "Erika Mustermann");
+    assert_eq!(unsealed.private().diagnosis, ["Arrhythmia"]);
+    assert_eq!(unsealed.public().ward, "Cardiology");
+
+    // the ciphertext can not be moved to a record with different public fields
+    let other = Patient::from_private(PatientPrivate {
+        id: 8,
+        ward: "Cardiology".into(),
+        name: "Max Mustermann".into(),
+        diagnosis: vec![],
+        r#type: 'B',
+    });
+    let mut swapped = other.seal(&cipher)?;
+    swapped.ciphertext = sealed.ciphertext.clone();
+    assert!(matches!(swapped.unseal(&cipher), Err(SealError::Cipher)));
+
+    // a different key can not unseal the data
+    let other_cipher = ChaCha20Poly1305Cipher::new(&ChaCha20Poly1305Cipher::generate_key());
+    assert!(matches!(
+        sealed.clone().unseal(&other_cipher),
+        Err(SealError::Cipher)
+    ));
+
+    // neither can a tampered ciphertext
+    let mut tampered = sealed;
+    *tampered.ciphertext.last_mut().unwrap() ^= 1;
+    assert!(matches!(tampered.unseal(&cipher), Err(SealError::Cipher)));
+
+    let pin = Pin::from_private(PinPrivate(1, 1234)).seal(&cipher)?;
+    assert_eq!(pin.0, 1);
+    let pin = pin.unseal(&cipher)?;
+    dbg!(pin.private());
+
+    Ok(())
+}
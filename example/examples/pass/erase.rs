@@ -0,0 +1,53 @@
+use sanitizeable::{sanitizeable, ErasureReport, Sanitizeable};
+
+#[sanitizeable(erase)]
+#[derive(Debug)]
+struct Customer {
+    pub id: u32,
+    pub country: String,
+    pub orders: u32,
+
+    #[private]
+    pub name: String,
+    #[private(erase_with = "redacted_email")]
+    pub email: String,
+    #[private]
+    pub phone: Option<String>,
+}
+
+fn redacted_email() -> String {
+    "erased@example.com".into()
+}
+
+#[sanitizeable(erase)]
+#[derive(Debug)]
+struct Unnamed(u64, #[private] String);
+
+fn main() {
+    let mut customer = Customer::from_private(CustomerPrivate {
+        id: 12,
+        country: "DE".into(),
+        orders: 3,
+        name: "Max Mustermann".into(),
+        email: "max@example.com".into(),
+        phone: Some("+49 123 456789".into()),
+    });
+
+    let report: ErasureReport = customer.erase_private();
+    assert_eq!(report.erased, ["name", "email", "phone"]);
+
+    // the container is still usable
+    customer.public_mut().orders += 1;
+
+    let customer = customer.into_private();
+    assert_eq!(customer.id, 12);
+    assert_eq!(customer.country, "DE");
+    assert_eq!(customer.orders, 4);
+    assert_eq!(customer.name, "");
+    assert_eq!(customer.email, "erased@example.com");
+    assert_eq!(customer.phone, None);
+
+    let mut unnamed = Unnamed::from_private(UnnamedPrivate(1, "secret".into()));
+    assert_eq!(unnamed.erase_private().erased, ["1"]);
+    dbg!(unnamed.private());
+}
@@ -0,0 +1,94 @@
+use async_graphql::{Context, EmptyMutation, EmptySubscription, Guard, Object, Request, Schema};
+use sanitizeable::{sanitizeable, Sanitizeable};
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum Role {
+    User,
+    Admin,
+}
+
+struct RoleGuard(Role);
+
+impl Guard for RoleGuard {
+    async fn check(&self, ctx: &Context<'_>) -> async_graphql::Result<()> {
+        if ctx.data_opt::<Role>() == Some(&self.0) {
+            Ok(())
+        } else {
+            Err("forbidden".into())
+        }
+    }
+}
+
+/// A user of our service
+#[sanitizeable(graphql)]
+#[public_attr::derive(Clone)]
+struct Account {
+    /// The account's display name
+    pub display_name: String,
+    pub karma: i64,
+    #[private(graphql_guard = "RoleGuard(Role::Admin)")]
+    pub email: String,
+    #[private]
+    pub password_hash: String,
+}
+
+fn load_account() -> Account {
+    Account::from_private(AccountPrivate {
+        display_name: "soruh".to_string(),
+        karma: 5,
+        email: "mail@example.com".to_string(),
+        password_hash: "hunter2".to_string(),
+    })
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn account(&self) -> Account {
+        load_account()
+    }
+
+    async fn account_public(&self) -> AccountPublic {
+        load_account().public().clone()
+    }
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let sdl = schema.sdl();
+    println!("{}", sdl);
+    assert!(!sdl.contains("passwordHash"));
+    assert!(sdl.contains("The account's display name"));
+
+    let response = schema
+        .execute("{ accountPublic { displayName karma } account { displayName karma } }")
+        .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["accountPublic"]["displayName"], "soruh");
+    assert_eq!(data["account"]["karma"], 5);
+
+    let response = schema.execute("{ accountPublic { email } }").await;
+    assert!(!response.errors.is_empty());
+
+    let response = schema.execute("{ account { passwordHash } }").await;
+    assert!(!response.errors.is_empty());
+
+    let response = schema.execute("{ account { email } }").await;
+    assert_eq!(response.errors[0].message, "forbidden");
+
+    let response = schema
+        .execute(Request::new("{ account { email } }").data(Role::User))
+        .await;
+    assert_eq!(response.errors[0].message, "forbidden");
+
+    let response = schema
+        .execute(Request::new("{ account { email } }").data(Role::Admin))
+        .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["account"]["email"], "mail@example.com");
+}
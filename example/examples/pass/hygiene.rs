@@ -0,0 +1,70 @@
+//! The generated code only uses absolute paths, so it keeps working when common names are shadowed
+//! and when `sanitizeable` is only reachable through a re-export.
+#![allow(dead_code, non_camel_case_types, unused_macros)]
+
+mod facade {
+    pub use sanitizeable::*;
+}
+
+mod core {}
+struct Ok;
+struct Err;
+struct Some;
+struct None;
+struct Option;
+struct Result;
+struct Vec;
+struct Default;
+struct Clone;
+struct Drop;
+struct u8;
+struct str;
+
+macro_rules! stringify {
+    ($($tokens:tt)*) => {
+        compile_error!("the generated code used a shadowed `stringify!`")
+    };
+}
+
+#[facade::sanitizeable(
+    crate = "crate::facade",
+    seal,
+    sign,
+    erase,
+    catalogue,
+    valuable,
+    log,
+    typescript
+)]
+#[public_attr::derive(Clone, serde::Serialize)]
+struct Account {
+    pub id: u64,
+    pub name: ::std::string::String,
+    #[private]
+    pub password: ::std::string::String,
+}
+
+fn main() {
+    use facade::Sanitizeable;
+
+    let account = Account::from_private(AccountPrivate {
+        id: 1,
+        name: "soruh".into(),
+        password: "hunter2".into(),
+    });
+    assert_eq!(account.public().name, "soruh");
+    assert_eq!(Account::FIELDS.len(), 3);
+
+    let cipher = facade::encryption::ChaCha20Poly1305Cipher::new(
+        &facade::encryption::ChaCha20Poly1305Cipher::generate_key(),
+    );
+    let sealed = account.seal(&cipher).unwrap();
+    let mut account = sealed.unseal(&cipher).unwrap();
+    assert_eq!(account.private().password, "hunter2");
+
+    let key = facade::signing::SigningKey::new(*b"key");
+    assert!(account.sign_public(&key).unwrap().verify(&key).is_ok());
+
+    account.erase_private();
+    assert_eq!(account.into_private().password, "");
+}
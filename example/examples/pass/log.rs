@@ -0,0 +1,78 @@
+use log::{
+    kv::{Error, Key, Source, Value, VisitSource},
+    Level, Log, Metadata, Record,
+};
+use sanitizeable::{sanitizeable, Sanitizeable};
+use std::sync::Mutex;
+
+#[sanitizeable(log)]
+#[public_attr::derive(serde::Serialize)]
+struct User {
+    pub name: String,
+    pub karma: i64,
+    #[private]
+    #[phantom_attr::serde(skip)]
+    pub password: String,
+}
+
+/// Collects all key-value pairs of a source
+struct Pairs(Vec<String>);
+
+impl<'kvs> VisitSource<'kvs> for Pairs {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), Error> {
+        self.0.push(format!("{}={}", key, value));
+        Ok(())
+    }
+}
+
+/// Records the key-value pairs of all logged records
+struct Recorder(Mutex<Vec<String>>);
+
+impl Log for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        let mut pairs = Pairs(Vec::new());
+        record.key_values().visit(&mut pairs).unwrap();
+        self.0.lock().unwrap().extend(pairs.0);
+    }
+
+    fn flush(&self) {}
+}
+
+static RECORDER: Recorder = Recorder(Mutex::new(Vec::new()));
+
+fn main() {
+    log::set_logger(&RECORDER).unwrap();
+    log::set_max_level(log::LevelFilter::Info);
+
+    let user = User::from_private(UserPrivate {
+        name: "soruh".to_string(),
+        karma: 5,
+        password: "hunter2".to_string(),
+    });
+
+    let mut pairs = Pairs(Vec::new());
+    user.visit(&mut pairs).unwrap();
+    assert_eq!(pairs.0, ["name=soruh", "karma=5"]);
+    assert_eq!(user.count(), 2);
+    assert!(user.get(Key::from_str("password")).is_none());
+
+    log::info!(user; "login");
+    let captured = RECORDER.0.lock().unwrap().drain(..).collect::<Vec<_>>();
+    assert_eq!(captured.len(), 1);
+    assert!(captured[0].starts_with("user="));
+    assert!(captured[0].contains("soruh"));
+    assert!(!captured[0].contains("password"));
+
+    RECORDER.log(
+        &Record::builder()
+            .level(Level::Info)
+            .args(format_args!("login"))
+            .key_values(&user)
+            .build(),
+    );
+    assert_eq!(*RECORDER.0.lock().unwrap(), ["name=soruh", "karma=5"]);
+}
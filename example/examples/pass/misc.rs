@@ -1,5 +1,3 @@
-#![feature(untagged_unions)]
-
 use sanitizeable::{sanitizeable, Sanitizeable};
 
 // Test generics
@@ -0,0 +1,55 @@
+use sanitizeable::sanitizeable;
+use utoipa::OpenApi;
+
+/// A user of our service
+#[sanitizeable(openapi)]
+#[derive(Debug)]
+#[public_attr::derive(serde::Serialize)]
+#[public_attr::schema(example = json!({"name": "A user", "karma": 5}))]
+struct User {
+    /// The user's display name
+    pub name: String,
+    #[public_attr::doc = "Karma earned by posting"]
+    #[private_attr::doc = "Karma including internal adjustments"]
+    pub karma: i64,
+    #[private]
+    pub password_hash: String,
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(UserPublic)))]
+struct ApiDoc;
+
+fn main() {
+    let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    println!("{:#}", spec);
+
+    let schemas = spec["components"]["schemas"].as_object().unwrap();
+    assert_eq!(schemas.keys().collect::<Vec<_>>(), ["UserPublic"]);
+
+    let user = &schemas["UserPublic"];
+    assert_eq!(user["description"], "A user of our service");
+    assert_eq!(user["example"]["name"], "A user");
+
+    let properties = user["properties"].as_object().unwrap();
+    assert_eq!(properties.keys().collect::<Vec<_>>(), ["karma", "name"]);
+    assert_eq!(
+        properties["karma"]["description"],
+        "Karma earned by posting"
+    );
+    assert_eq!(properties["name"]["description"], "The user's display name");
+
+    assert!(!spec.to_string().contains("password_hash"));
+
+    // the JSON of the public variant has exactly the documented properties
+    let user = <User as sanitizeable::Sanitizeable>::from_private(UserPrivate {
+        name: "A user".into(),
+        karma: 5,
+        password_hash: "$argon2id$...".into(),
+    });
+    let json = serde_json::to_value(sanitizeable::Sanitizeable::public(&user)).unwrap();
+    assert_eq!(
+        json.as_object().unwrap().keys().collect::<Vec<_>>(),
+        properties.keys().collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,70 @@
+use sanitizeable::{sanitizeable, Sanitizeable};
+use std::marker::PhantomData;
+
+/// The id of a row in the table of `T`, which does not hold a `T`
+struct Id<T>(u64, PhantomData<T>);
+
+// In private fields `Self` and `Comment` both refer to the container,
+// so every reply decides on its own which variant is visible
+
+#[sanitizeable(container_name = "Comment")]
+struct CommentData {
+    pub text: String,
+    #[private]
+    pub author_ip: String,
+    #[private]
+    pub replies: Vec<Self>,
+    #[private]
+    pub parent: Option<Box<CommentData>>,
+    // Public fields can refer to the container as long as they do not hold it
+    pub parent_id: Option<Id<Self>>,
+    pub kind: PhantomData<fn() -> Comment>,
+}
+
+fn comment(text: &str, author_ip: &str, replies: Vec<Comment>) -> Comment {
+    Comment::from_private(CommentDataPrivate {
+        text: text.to_string(),
+        author_ip: author_ip.to_string(),
+        replies,
+        parent: None,
+        parent_id: None,
+        kind: PhantomData,
+    })
+}
+
+fn count(thread: &Comment) -> usize {
+    1 + thread.private().replies.iter().map(count).sum::<usize>()
+}
+
+fn main() {
+    let mut thread = comment(
+        "first",
+        "127.0.0.1",
+        vec![
+            comment("reply", "127.0.0.2", vec![]),
+            comment(
+                "another reply",
+                "127.0.0.3",
+                vec![comment("nested", "127.0.0.4", vec![])],
+            ),
+        ],
+    );
+
+    assert_eq!(count(&thread), 4);
+    assert_eq!(thread.public().text, "first");
+    assert_eq!(
+        thread.private().replies[1].private().replies[0]
+            .public()
+            .text,
+        "nested"
+    );
+    assert_eq!(thread.private().replies[0].private().author_ip, "127.0.0.2");
+
+    thread.private_mut().parent = Some(Box::new(comment("root", "127.0.0.5", vec![])));
+    thread.public_mut().parent_id = Some(Id(5, PhantomData));
+    let parent_id: &Option<Id<Comment>> = &thread.public().parent_id;
+    assert_eq!(parent_id.as_ref().unwrap().0, 5);
+
+    let private = thread.into_private();
+    assert_eq!(private.parent.unwrap().private().author_ip, "127.0.0.5");
+}
@@ -0,0 +1,58 @@
+use sanitizeable::{sanitizeable, Sanitizeable};
+
+/// A registered user
+#[sanitizeable(json_schema, private_json_schema)]
+#[derive(Debug)]
+#[public_attr::derive(serde::Serialize)]
+struct User {
+    /// The user's display name
+    pub name: String,
+    #[public_attr::doc = "Only the domain of the email is shown publicly"]
+    #[private_attr::doc = "The full email address"]
+    pub email: String,
+    #[private]
+    pub pin: u16,
+}
+
+fn main() {
+    let public_schema = serde_json::to_value(schemars::schema_for!(UserPublic)).unwrap();
+    let private_schema = serde_json::to_value(schemars::schema_for!(UserPrivate)).unwrap();
+
+    println!("{:#}", public_schema);
+
+    let properties = public_schema["properties"].as_object().unwrap();
+    assert_eq!(
+        properties.keys().collect::<Vec<_>>(),
+        ["email", "name"],
+        "the placeholder for `pin` must not show up"
+    );
+    assert_eq!(public_schema["description"], "A registered user");
+    assert_eq!(
+        properties["email"]["description"],
+        "Only the domain of the email is shown publicly"
+    );
+    assert_eq!(properties["name"]["description"], "The user's display name");
+
+    let properties = private_schema["properties"].as_object().unwrap();
+    assert_eq!(
+        properties.keys().collect::<Vec<_>>(),
+        ["email", "name", "pin"]
+    );
+    assert_eq!(properties["email"]["description"], "The full email address");
+
+    // The schema matches what `UserPublic` serializes to
+    let user = User::from_private(UserPrivate {
+        name: "A user".into(),
+        email: "example.com".into(),
+        pin: 1337,
+    });
+    let json = serde_json::to_value(user.public()).unwrap();
+    assert_eq!(
+        json.as_object().unwrap().keys().collect::<Vec<_>>(),
+        public_schema["properties"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .collect::<Vec<_>>()
+    );
+}
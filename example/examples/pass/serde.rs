@@ -1,4 +1,3 @@
-#![feature(untagged_unions)]
 use sanitizeable::{sanitizeable, Sanitizeable};
 
 #[sanitizeable]
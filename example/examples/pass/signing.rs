@@ -0,0 +1,52 @@
+use sanitizeable::{
+    sanitizeable,
+    signing::{SignatureError, Signed, SigningKey},
+    Sanitizeable,
+};
+
+#[sanitizeable(sign)]
+#[derive(Debug)]
+#[public_attr::derive(Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Offer {
+    pub product: String,
+    pub price_cents: u64,
+    #[private]
+    pub margin_cents: u64,
+}
+
+fn main() -> Result<(), SignatureError> {
+    let key = SigningKey::new(*b"a secret only we know");
+
+    let mut offer = Offer::from_private(OfferPrivate {
+        product: "Printer".into(),
+        price_cents: 9900,
+        margin_cents: 4000,
+    });
+
+    let signed: Signed<OfferPublic> = offer.sign_public(&key)?;
+
+    // The signed value makes a round trip through a partner
+    let json = serde_json::to_string(&signed).unwrap();
+    let returned: Signed<OfferPublic> = serde_json::from_str(&json).unwrap();
+    assert_eq!(returned.verify(&key)?, offer.public());
+
+    // Changing private data does not invalidate the signature
+    offer.private_mut().margin_cents = 0;
+    assert_eq!(offer.sign_public(&key)?.tag(), signed.tag());
+
+    // Tampering with the public fields does
+    let mut tampered = returned.clone();
+    tampered.unverified_mut().price_cents = 1;
+    assert!(matches!(
+        tampered.verify(&key),
+        Err(SignatureError::Mismatch)
+    ));
+
+    // And so does using another key
+    assert!(matches!(
+        returned.verify(&SigningKey::new(*b"a guess")),
+        Err(SignatureError::Mismatch)
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use sanitizeable::{sanitizeable, Sanitizeable};
+use sqlx::sqlite::SqlitePool;
+
+#[sanitizeable(sqlx)]
+#[derive(Debug)]
+struct User {
+    pub id: i64,
+    pub name: String,
+    #[private]
+    pub password_hash: String,
+    pub karma: i64,
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    assert_eq!(User::PUBLIC_COLUMNS, ["id", "name", "karma"]);
+    assert_eq!(
+        User::PRIVATE_COLUMNS,
+        ["id", "name", "karma", "password_hash"]
+    );
+
+    let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+
+    sqlx::query(
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL, karma INTEGER NOT NULL, password_hash TEXT NOT NULL)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO users VALUES (1, 'soruh', 5, 'hunter2')")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let query = format!("SELECT {} FROM users", User::PRIVATE_COLUMNS.join(", "));
+    let user: User = sqlx::query_as(&query).fetch_one(&pool).await.unwrap();
+    assert_eq!(user.private().password_hash, "hunter2");
+    assert_eq!(user.public().name, "soruh");
+
+    // The public variant can be loaded without ever fetching the password hash
+    let query = format!("SELECT {} FROM users", User::PUBLIC_COLUMNS.join(", "));
+    let public: UserPublic = sqlx::query_as(&query).fetch_one(&pool).await.unwrap();
+    assert_eq!(public.id, 1);
+    assert_eq!(public.karma, 5);
+
+    // Loading the container requires the private columns
+    let result = sqlx::query_as::<_, User>(&query).fetch_one(&pool).await;
+    assert!(matches!(result, Err(sqlx::Error::ColumnNotFound(_))));
+}
@@ -0,0 +1,172 @@
+use sanitizeable::{sanitizeable, Sanitizeable};
+use std::collections::HashMap;
+
+#[sanitizeable(typescript)]
+#[derive(Debug)]
+#[public_attr::derive(serde::Serialize)]
+struct Address {
+    pub city: String,
+    #[private]
+    pub street: String,
+}
+
+/// A user as shown to other users
+#[sanitizeable(typescript)]
+#[derive(Debug)]
+#[public_attr::derive(serde::Serialize)]
+struct User {
+    pub id: u64,
+    /// The name shown on the profile
+    pub name: String,
+    pub verified: bool,
+    pub nickname: Option<String>,
+    pub tags: Vec<String>,
+    pub scores: HashMap<String, f64>,
+    pub birthday: (u16, u8, u8),
+    pub addresses: Vec<Option<AddressPublic>>,
+
+    #[private]
+    #[phantom_attr::serde(skip)]
+    pub password_hash: String,
+}
+
+#[sanitizeable(typescript)]
+#[derive(Debug)]
+#[public_attr::derive(serde::Serialize)]
+#[public_attr::serde(rename_all = "camelCase")]
+struct Profile {
+    pub display_name: String,
+    #[public_attr::serde(rename = "avatar-url")]
+    pub avatar_url: String,
+    #[private]
+    pub session_token: String,
+}
+
+#[sanitizeable(typescript)]
+#[derive(Debug)]
+#[public_attr::derive(serde::Serialize)]
+struct Generic<T>(Box<T>, #[private] u64);
+
+// Holding containers does not make the public variant serializable,
+// but the definition still refers to the interfaces of their public variants
+#[sanitizeable(typescript)]
+struct Order {
+    pub id: u64,
+    pub shipping: Address,
+    pub previous: Vec<Option<Address>>,
+    pub billing: AddressPublic,
+    #[private]
+    pub card: String,
+}
+
+/// Check that the properties of an interface are exactly the keys `serde_json` produces,
+/// and that the ones typed `null` are `null`
+fn assert_interface_matches(definition: &str, json: serde_json::Value) {
+    let properties = definition
+        .lines()
+        .filter(|line| line.starts_with("  ") && !line.trim_start().starts_with("/**"))
+        .map(|line| {
+            let (name, ty) = line.trim().split_once(": ").unwrap();
+            (name.trim_matches('"').to_string(), ty.trim_end_matches(';'))
+        })
+        .collect::<Vec<_>>();
+
+    let json = json.as_object().unwrap();
+    let mut names = properties
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+    assert_eq!(names, json.keys().collect::<Vec<_>>(), "{definition}");
+
+    for (name, ty) in properties {
+        if ty == "null" {
+            assert!(json[&name].is_null(), "{}: {}", name, ty);
+        }
+    }
+}
+
+fn main() {
+    assert_eq!(
+        AddressPublic::typescript_definition(),
+        "export interface AddressPublic {\n  city: string;\n  _street: null;\n}\n"
+    );
+
+    assert_eq!(
+        UserPublic::typescript_definition(),
+        r#"/** A user as shown to other users */
+export interface UserPublic {
+  id: number;
+  /** The name shown on the profile */
+  name: string;
+  verified: boolean;
+  nickname: string | null;
+  tags: string[];
+  scores: Record<string, number>;
+  birthday: [number, number, number];
+  addresses: (AddressPublic | null)[];
+}
+"#
+    );
+
+    assert_eq!(
+        ProfilePublic::typescript_definition(),
+        "export interface ProfilePublic {\n  displayName: string;\n  \"avatar-url\": string;\n  sessionToken: null;\n}\n"
+    );
+
+    assert_eq!(
+        GenericPublic::<u8>::typescript_definition(),
+        "export type GenericPublic<T> = [T, null];\n"
+    );
+
+    assert_eq!(
+        OrderPublic::typescript_definition(),
+        r#"export interface OrderPublic {
+  id: number;
+  shipping: AddressPublic;
+  previous: (AddressPublic | null)[];
+  billing: AddressPublic;
+  _card: null;
+}
+"#
+    );
+
+    // The definitions describe what `serde_json` actually produces
+    let address = Address::from_private(AddressPrivate {
+        city: "Berlin".into(),
+        street: "Unter den Linden".into(),
+    });
+    assert_interface_matches(
+        AddressPublic::typescript_definition(),
+        serde_json::to_value(address.public()).unwrap(),
+    );
+
+    let user = User::from_private(UserPrivate {
+        id: 1,
+        name: "soruh".into(),
+        verified: true,
+        nickname: None,
+        tags: vec!["admin".into()],
+        scores: HashMap::new(),
+        birthday: (2000, 1, 1),
+        addresses: vec![],
+        password_hash: "$argon2id$...".into(),
+    });
+    assert_interface_matches(
+        UserPublic::typescript_definition(),
+        serde_json::to_value(user.public()).unwrap(),
+    );
+
+    let profile = Profile::from_private(ProfilePrivate {
+        display_name: "soruh".into(),
+        avatar_url: "https://example.com/avatar.png".into(),
+        session_token: "secret".into(),
+    });
+    assert_interface_matches(
+        ProfilePublic::typescript_definition(),
+        serde_json::to_value(profile.public()).unwrap(),
+    );
+
+    let generic = Generic::from_private(GenericPrivate(Box::new(1u8), 2));
+    assert_eq!(serde_json::to_string(generic.public()).unwrap(), "[1,null]");
+}
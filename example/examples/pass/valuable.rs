@@ -0,0 +1,110 @@
+use sanitizeable::{sanitizeable, Sanitizeable};
+use std::sync::{Arc, Mutex};
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Metadata, Subscriber,
+};
+use valuable::{NamedValues, Valuable, Value};
+
+#[sanitizeable(valuable)]
+struct User {
+    pub name: String,
+    pub karma: i64,
+    #[private]
+    pub password: String,
+}
+
+#[sanitizeable(valuable)]
+struct Point(pub i32, #[private] pub i32);
+
+/// Collects the names and values of all visited fields
+#[derive(Default)]
+struct Fields(Vec<String>);
+
+impl valuable::Visit for Fields {
+    fn visit_value(&mut self, value: Value<'_>) {
+        if let Value::Structable(structable) = value {
+            structable.visit(self);
+        }
+    }
+
+    fn visit_named_fields(&mut self, named_values: &NamedValues<'_>) {
+        for (field, value) in named_values {
+            self.0.push(format!("{}={:?}", field.name(), value));
+        }
+    }
+
+    fn visit_unnamed_fields(&mut self, values: &[Value<'_>]) {
+        for value in values {
+            self.0.push(format!("{:?}", value));
+        }
+    }
+}
+
+/// Records the fields of all events
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+impl Visit for Recorder {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("{}={:?}", field.name(), value));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+        span::Id::from_u64(1)
+    }
+
+    fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        event.record(&mut self.clone());
+    }
+
+    fn enter(&self, _: &span::Id) {}
+
+    fn exit(&self, _: &span::Id) {}
+}
+
+fn main() {
+    let user = User::from_private(UserPrivate {
+        name: "soruh".to_string(),
+        karma: 5,
+        password: "hunter2".to_string(),
+    });
+
+    let mut fields = Fields::default();
+    valuable::Visit::visit_value(&mut fields, user.as_value());
+    assert_eq!(fields.0, [r#"name="soruh""#, "karma=5"]);
+
+    let mut fields = Fields::default();
+    valuable::Visit::visit_value(
+        &mut fields,
+        Point::from_private(PointPrivate(1, 2)).as_value(),
+    );
+    assert_eq!(fields.0, ["1"]);
+
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), || {
+        tracing::info!(user = sanitizeable::tracing::public(&user), "login");
+    });
+
+    let events = recorder.0.lock().unwrap();
+    assert_eq!(
+        *events,
+        [
+            r#"message=login"#,
+            r#"user=UserPublic { name: "soruh", karma: 5 }"#,
+        ]
+    );
+}
@@ -0,0 +1,64 @@
+use sanitizeable::sanitizeable;
+use sqlx::{sqlite::SqlitePool, Row};
+
+#[sanitizeable(sqlx, view_sql)]
+struct Customer {
+    pub id: i64,
+    #[column = "display_name"]
+    pub name: String,
+    #[private]
+    pub email: String,
+    #[private]
+    #[column = "card"]
+    pub credit_card: String,
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let sql = Customer::create_public_view_sql("customers", "reporting.customers_public");
+    assert_eq!(
+        sql,
+        r#"CREATE VIEW "reporting"."customers_public" AS SELECT "id", "display_name" FROM "customers""#
+    );
+    assert_eq!(Customer::PUBLIC_COLUMNS, ["id", "display_name"]);
+    assert_eq!(
+        Customer::PRIVATE_COLUMNS,
+        ["id", "display_name", "email", "card"]
+    );
+
+    let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+    sqlx::query(
+        "CREATE TABLE customers (id INTEGER PRIMARY KEY, display_name TEXT, email TEXT, card TEXT)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO customers VALUES (1, 'soruh', 'mail@example.com', '4242')")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    sqlx::query(&Customer::create_public_view_sql(
+        "customers",
+        "customers_public",
+    ))
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let columns: Vec<String> =
+        sqlx::query("SELECT name FROM pragma_table_info('customers_public')")
+            .fetch_all(&pool)
+            .await
+            .unwrap()
+            .iter()
+            .map(|row| row.get(0))
+            .collect();
+    assert_eq!(columns, Customer::PUBLIC_COLUMNS);
+
+    let public: CustomerPublic = sqlx::query_as("SELECT * FROM customers_public")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(public.name, "soruh");
+}
@@ -0,0 +1,75 @@
+use sanitizeable::audit::{merge_manifest, AUDIT_DIR_ENV};
+
+#[test]
+fn audit_manifest() {
+    let dir = std::env::temp_dir().join(format!("sanitizeable-audit-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    // This test runs in its own process, so setting the variable does not affect `tests/trybuild.rs`
+    std::env::set_var(AUDIT_DIR_ENV, &dir);
+
+    let t = trybuild::TestCases::new();
+    t.pass("examples/pass/audit.rs");
+    drop(t);
+
+    let manifest: serde_json::Value = serde_json::from_str(&merge_manifest(&dir).unwrap()).unwrap();
+
+    let entries = manifest.as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+    check_entries(entries);
+
+    // Clearing the directory makes cargo build the example again, which also replaces outdated entries
+    let krate = entries[0]["crate"].as_str().unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join(format!("{}.moved.json", krate)), "{}").unwrap();
+
+    let t = trybuild::TestCases::new();
+    t.pass("examples/pass/audit.rs");
+    drop(t);
+
+    let manifest: serde_json::Value = serde_json::from_str(&merge_manifest(&dir).unwrap()).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let entries = manifest.as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+    check_entries(entries);
+}
+
+fn check_entries(entries: &[serde_json::Value]) {
+    let account = &entries[0];
+    assert_eq!(account["type"], "Account");
+    assert_eq!(account["public"], "AccountView");
+    assert_eq!(account["private"], "AccountPrivate");
+    assert!(!account["crate"].as_str().unwrap().is_empty());
+    assert!(account["file"].as_str().unwrap().ends_with("audit.rs"));
+    assert_eq!(account["attributes"]["normal"][0], "derive(Debug)");
+    assert_eq!(account["attributes"]["public"][0], "derive(Clone)");
+
+    let fields = account["fields"].as_array().unwrap();
+    let names = fields
+        .iter()
+        .map(|field| field["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["handle", "password_hash", "email"]);
+
+    assert_eq!(fields[0]["privacy"], "public");
+    assert_eq!(
+        fields[0]["attributes"]["normal"][0],
+        "doc = \" Public handle of the account\""
+    );
+    assert_eq!(fields[1]["privacy"], "public");
+    assert_eq!(
+        fields[1]["attributes"]["private"][0],
+        "doc = \"Only ever compared against hashes\""
+    );
+    assert_eq!(fields[2]["privacy"], "private");
+    assert_eq!(fields[2]["type"], "String");
+    assert_eq!(fields[2]["labels"], serde_json::json!(["PII"]));
+    assert_eq!(fields[2]["retention"], "90d");
+
+    let legacy = &entries[1];
+    assert_eq!(legacy["type"], "Account");
+    assert_eq!(legacy["public"], "AccountPublic");
+    assert_eq!(legacy["fields"].as_array().unwrap().len(), 2);
+}
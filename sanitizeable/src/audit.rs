@@ -0,0 +1,35 @@
+//! Merging of the audit manifest written by `#[sanitizeable]`
+//!
+//! If the environment variable [`AUDIT_DIR_ENV`] is set while building, the macro writes
+//! one JSON entry per annotated struct into that directory, describing its public and
+//! private fields, their classification and the attributes applied to each variant.
+//! Whenever a crate is built, the entries of its previous build are replaced, so structs that moved
+//! or were removed do not show up twice. Cargo also builds a crate again if one of its entries is missing,
+//! so clearing the directory is enough to get a fresh report on the next build.
+
+use std::{io, path::Path};
+
+/// The environment variable read by the macro to find the directory entries are written to
+pub const AUDIT_DIR_ENV: &str = "SANITIZEABLE_AUDIT_DIR";
+
+/// Merge all entries in `dir` into a single JSON array, sorted by file name
+pub fn merge_manifest(dir: impl AsRef<Path>) -> io::Result<String> {
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut entries = Vec::with_capacity(paths.len());
+    for path in paths {
+        entries.push(std::fs::read_to_string(path)?.trim_end().to_string());
+    }
+
+    Ok(format!("[\n{}\n]\n", entries.join(",\n")))
+}
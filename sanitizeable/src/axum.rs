@@ -0,0 +1,43 @@
+//! Integration with `axum`
+//!
+//! Structs annotated with `#[sanitizeable(axum)]` implement `IntoResponse` for their container,
+//! which renders only the public variant as JSON. [`Json`] does the same for any container
+//! and additionally works as an extractor that reads the private variant from a JSON request body.
+
+use crate::Sanitizeable;
+use ::axum::{
+    extract::{rejection::JsonRejection, FromRequest, Request},
+    response::{IntoResponse, Response},
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// A JSON body containing a container
+///
+/// As an extractor this deserializes the private variant, since that is what a client sends.
+/// As a response this serializes only the public variant.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Json<T>(pub T);
+
+impl<T, S> FromRequest<S> for Json<T>
+where
+    T: Sanitizeable,
+    T::Private: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = JsonRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let ::axum::Json(private) = ::axum::Json::<T::Private>::from_request(req, state).await?;
+        Ok(Self(T::from_private(private)))
+    }
+}
+
+impl<T> IntoResponse for Json<T>
+where
+    T: Sanitizeable,
+    T::Public: Serialize,
+{
+    fn into_response(self) -> Response {
+        ::axum::Json(self.0.public()).into_response()
+    }
+}
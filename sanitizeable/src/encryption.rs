@@ -0,0 +1,108 @@
+//! Encryption of private fields at rest
+//!
+//! Structs annotated with `#[sanitizeable(seal)]` get a `seal` method on their container
+//! which encrypts all `#[private]` fields into a single blob using a [`Cipher`],
+//! while keeping the public fields in the clear.
+
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng, Payload},
+    ChaCha20Poly1305, Key, Nonce,
+};
+
+/// An authenticated cipher used to seal the private fields of a container
+pub trait Cipher {
+    /// Encrypt and authenticate `plaintext` together with `associated_data`
+    fn encrypt(&self, plaintext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>, SealError>;
+
+    /// Decrypt `ciphertext`, failing if it or `associated_data` have been tampered with
+    fn decrypt(&self, ciphertext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>, SealError>;
+}
+
+/// A [`Cipher`] using ChaCha20-Poly1305 with a random nonce per message
+///
+/// The nonce is stored in front of the ciphertext.
+pub struct ChaCha20Poly1305Cipher {
+    cipher: ChaCha20Poly1305,
+}
+
+const NONCE_LENGTH: usize = 12;
+
+impl ChaCha20Poly1305Cipher {
+    pub fn new(key: &[u8; 32]) -> Self {
+        Self {
+            cipher: ChaCha20Poly1305::new(Key::from_slice(key)),
+        }
+    }
+
+    /// Generate a new random key using the operating system's random number generator
+    pub fn generate_key() -> [u8; 32] {
+        ChaCha20Poly1305::generate_key(&mut OsRng).into()
+    }
+}
+
+impl Cipher for ChaCha20Poly1305Cipher {
+    fn encrypt(&self, plaintext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>, SealError> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let payload = Payload {
+            msg: plaintext,
+            aad: associated_data,
+        };
+
+        let mut out = nonce.to_vec();
+        out.extend(
+            self.cipher
+                .encrypt(&nonce, payload)
+                .map_err(|_| SealError::Cipher)?,
+        );
+        Ok(out)
+    }
+
+    fn decrypt(&self, ciphertext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>, SealError> {
+        if ciphertext.len() < NONCE_LENGTH {
+            return Err(SealError::Cipher);
+        }
+
+        let (nonce, msg) = ciphertext.split_at(NONCE_LENGTH);
+        let payload = Payload {
+            msg,
+            aad: associated_data,
+        };
+
+        self.cipher
+            .decrypt(Nonce::from_slice(nonce), payload)
+            .map_err(|_| SealError::Cipher)
+    }
+}
+
+/// The error returned when sealing or unsealing a container fails
+#[derive(Debug)]
+pub enum SealError {
+    /// Encryption failed or the ciphertext could not be authenticated
+    Cipher,
+    /// The private fields could not be (de)serialized
+    Encoding(bincode::Error),
+}
+
+impl From<bincode::Error> for SealError {
+    fn from(error: bincode::Error) -> Self {
+        Self::Encoding(error)
+    }
+}
+
+impl std::fmt::Display for SealError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cipher => write!(f, "failed to encrypt or authenticate private fields"),
+            Self::Encoding(error) => write!(f, "failed to encode private fields: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for SealError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Cipher => None,
+            Self::Encoding(error) => Some(error),
+        }
+    }
+}
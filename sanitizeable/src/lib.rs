@@ -1,5 +1,85 @@
 pub use sanitizeable_derive::sanitizeable;
 
+pub mod audit;
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "encryption")]
+pub mod encryption;
+#[cfg(feature = "signing")]
+pub mod signing;
+pub mod sql;
+#[cfg(feature = "tracing")]
+pub mod tracing;
+pub mod typescript;
+
+/// Re-exports used by the generated code. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "graphql")]
+    pub use async_graphql;
+    #[cfg(feature = "axum")]
+    pub use axum;
+    #[cfg(any(feature = "encryption", feature = "signing"))]
+    pub use bincode;
+    #[cfg(feature = "diesel")]
+    pub use diesel;
+    #[cfg(feature = "log")]
+    pub use log;
+    #[cfg(feature = "schemars")]
+    pub use schemars;
+    #[cfg(any(
+        feature = "encryption",
+        feature = "signing",
+        feature = "axum",
+        feature = "log"
+    ))]
+    pub use serde;
+    #[cfg(feature = "sqlx")]
+    pub use sqlx;
+    #[cfg(feature = "utoipa")]
+    pub use utoipa;
+    #[cfg(feature = "valuable")]
+    pub use valuable;
+
+    /// The size of the field returned by `field`, which is never called
+    pub const fn size_of_field<S, T>(_field: fn(&S) -> &T) -> usize {
+        core::mem::size_of::<T>()
+    }
+
+    /// The alignment of the field returned by `field`, which is never called
+    pub const fn align_of_field<S, T>(_field: fn(&S) -> &T) -> usize {
+        core::mem::align_of::<T>()
+    }
+}
+
+/// The fields reset by a call to `erase_private`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErasureReport {
+    /// The names of all erased fields (or their index for tuple structs)
+    pub erased: &'static [&'static str],
+}
+
+/// Whether a field is part of the public variant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Privacy {
+    Public,
+    Private,
+}
+
+/// The description of a field found in the `FIELDS` constant of a container
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// The name of the field (or its index in the private variant for tuple structs)
+    pub name: &'static str,
+    /// The type of the field as written in the struct definition, e.g. `Option<String>`
+    pub type_name: &'static str,
+    pub privacy: Privacy,
+    /// The labels given in `#[classify(...)]`, e.g. `PII`
+    pub labels: &'static [&'static str],
+    /// The value of `retention` given in `#[classify(...)]`
+    pub retention: Option<&'static str>,
+}
+
 pub trait Sanitizeable: Sized {
     type Public;
     type Private;
@@ -0,0 +1,116 @@
+//! Integrity protection of the public variant
+//!
+//! Structs annotated with `#[sanitizeable(sign)]` get a `sign_public` method on their container
+//! which computes a HMAC-SHA256 over a canonical encoding of only the public fields.
+//! The resulting [`Signed`] value can be handed out and later checked using [`Signed::verify`].
+
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// A deterministic encoding of all data that should be covered by a signature
+///
+/// This is implemented for the public variant of structs annotated with `#[sanitizeable(sign)]`
+/// by serializing the public fields with bincode. That is only canonical if equal values always serialize
+/// to the same bytes, which is not the case for `HashMap` or `HashSet` because their iteration order is random.
+/// Public fields mentioning them are rejected, use `BTreeMap` or `BTreeSet` instead.
+pub trait CanonicalEncoding {
+    fn canonical_encoding(&self) -> Result<Vec<u8>, SignatureError>;
+}
+
+/// A secret key used to sign and verify public variants
+pub struct SigningKey {
+    key: Vec<u8>,
+}
+
+impl SigningKey {
+    pub fn new(key: impl Into<Vec<u8>>) -> Self {
+        Self { key: key.into() }
+    }
+
+    fn mac(&self, value: &impl CanonicalEncoding) -> Result<HmacSha256, SignatureError> {
+        let mut mac =
+            HmacSha256::new_from_slice(&self.key).expect("HMAC can take keys of any size");
+        mac.update(&value.canonical_encoding()?);
+        Ok(mac)
+    }
+}
+
+/// A value together with a MAC over its canonical encoding
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Signed<T> {
+    value: T,
+    tag: Vec<u8>,
+}
+
+impl<T: CanonicalEncoding> Signed<T> {
+    pub fn sign(value: T, key: &SigningKey) -> Result<Self, SignatureError> {
+        let tag = key.mac(&value)?.finalize().into_bytes().to_vec();
+        Ok(Self { value, tag })
+    }
+
+    /// Recompute the MAC of the contained value and return the value if it matches the stored tag
+    pub fn verify(&self, key: &SigningKey) -> Result<&T, SignatureError> {
+        key.mac(&self.value)?
+            .verify_slice(&self.tag)
+            .map_err(|_| SignatureError::Mismatch)?;
+        Ok(&self.value)
+    }
+
+    /// Like [`Signed::verify`], but returns the value by value
+    pub fn into_verified(self, key: &SigningKey) -> Result<T, SignatureError> {
+        self.verify(key)?;
+        Ok(self.value)
+    }
+}
+
+impl<T> Signed<T> {
+    /// Access the contained value **without** checking the signature
+    pub fn unverified(&self) -> &T {
+        &self.value
+    }
+
+    /// Mutably access the contained value, which will invalidate the signature if it is changed
+    pub fn unverified_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
+    pub fn tag(&self) -> &[u8] {
+        &self.tag
+    }
+}
+
+/// The error returned when signing or verifying a public variant fails
+#[derive(Debug)]
+pub enum SignatureError {
+    /// The tag does not match the value, i.e. one of them has been tampered with
+    Mismatch,
+    /// The public fields could not be encoded
+    Encoding(bincode::Error),
+}
+
+impl From<bincode::Error> for SignatureError {
+    fn from(error: bincode::Error) -> Self {
+        Self::Encoding(error)
+    }
+}
+
+impl std::fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Mismatch => write!(f, "signature does not match the signed value"),
+            Self::Encoding(error) => write!(f, "failed to encode public fields: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for SignatureError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Mismatch => None,
+            Self::Encoding(error) => Some(error),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! SQL generated from the field split
+//!
+//! Containers of structs with named fields and `#[sanitizeable(view_sql)]` get a `create_public_view_sql` function,
+//! which uses [`create_view`] to expose only the public columns of a table.
+
+/// Quote a possibly schema qualified identifier like `reporting.users`,
+/// so it can be used in a SQL statement
+pub fn quote_identifier(identifier: &str) -> String {
+    identifier
+        .split('.')
+        .map(|part| format!("\"{}\"", part.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// A `CREATE VIEW` statement for `view`, which selects only `columns` from `table`
+pub fn create_view(table: &str, view: &str, columns: &[&str]) -> String {
+    let columns = columns
+        .iter()
+        .map(|column| quote_identifier(column))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "CREATE VIEW {} AS SELECT {} FROM {}",
+        quote_identifier(view),
+        columns,
+        quote_identifier(table)
+    )
+}
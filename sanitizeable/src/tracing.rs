@@ -0,0 +1,32 @@
+//! Recording containers in `tracing` events
+//!
+//! Containers do not implement `Debug`, and recording the private variant would leak private fields.
+//! Structs annotated with `#[sanitizeable(valuable)]` can instead be recorded with [`public`],
+//! e.g. `tracing::info!(user = sanitizeable::tracing::public(&user), "login")`.
+//!
+//! When building with `--cfg tracing_unstable`, the public variant is recorded as a `valuable::Value`,
+//! so subscribers supporting `valuable` see every public field as a separate key-value pair.
+//! Otherwise it is recorded using its `Debug` representation.
+
+use crate::Sanitizeable;
+use valuable::Valuable;
+
+/// Record only the public variant of `container`
+#[cfg(tracing_unstable)]
+pub fn public<T>(container: &T) -> impl tracing::Value + '_
+where
+    T: Sanitizeable,
+    T::Public: Valuable,
+{
+    tracing::field::valuable(container.public())
+}
+
+/// Record only the public variant of `container`
+#[cfg(not(tracing_unstable))]
+pub fn public<T>(container: &T) -> impl tracing::Value + '_
+where
+    T: Sanitizeable,
+    T::Public: Valuable,
+{
+    tracing::field::debug(container.public().as_value())
+}
@@ -0,0 +1,53 @@
+//! Names used by the TypeScript definitions generated for the public variant
+//!
+//! Public variants of structs with `#[sanitizeable(typescript)]` implement [`TypeScriptInterface`].
+//! A field holding a container, e.g. `address: Address`, is described by the interface of its public variant,
+//! so the definition refers to `AddressPublic` instead of an `Address` interface which is never generated.
+
+use crate::Sanitizeable;
+use core::marker::PhantomData;
+
+/// A type with a generated TypeScript interface
+pub trait TypeScriptInterface {
+    /// The name of the interface, without type parameters
+    const NAME: &'static str;
+}
+
+/// Looks up the interface name of `T` while the definition is built
+///
+/// Calling `(&Probe::<T>::new()).interface_name(fallback)` resolves to [`ContainerName`] if `T` is a container
+/// whose public variant has an interface and to [`OtherName`], which returns `fallback`, otherwise.
+#[doc(hidden)]
+pub struct Probe<T: ?Sized>(PhantomData<T>);
+
+impl<T: ?Sized> Probe<T> {
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait ContainerName {
+    fn interface_name(&self, fallback: &'static str) -> &'static str;
+}
+
+impl<T: Sanitizeable> ContainerName for Probe<T>
+where
+    T::Public: TypeScriptInterface,
+{
+    fn interface_name(&self, _fallback: &'static str) -> &'static str {
+        <T::Public as TypeScriptInterface>::NAME
+    }
+}
+
+#[doc(hidden)]
+pub trait OtherName {
+    fn interface_name(&self, fallback: &'static str) -> &'static str;
+}
+
+impl<T: ?Sized> OtherName for &Probe<T> {
+    fn interface_name(&self, fallback: &'static str) -> &'static str {
+        fallback
+    }
+}
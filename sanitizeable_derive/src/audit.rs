@@ -0,0 +1,95 @@
+use proc_macro::Diagnostic;
+use sanitizeable_derive_core::{audit_manifest_entry, ManifestEntry};
+use std::{path::Path, sync::Mutex};
+
+/// The environment variable naming the directory that manifest entries are written to
+const AUDIT_DIR_ENV: &str = "SANITIZEABLE_AUDIT_DIR";
+
+/// The crates whose old entries were already removed by this process
+///
+/// The compiler expands every macro of a crate whenever it builds it, so the first expansion
+/// can remove the entries of the previous build, e.g. of a struct that moved or was deleted.
+static CLEARED_CRATES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Write a JSON manifest entry describing the struct to `$SANITIZEABLE_AUDIT_DIR`, if it is set
+pub fn write_manifest_entry(args: &proc_macro::TokenStream, input: &proc_macro::TokenStream) {
+    let Ok(dir) = proc_macro::tracked::env_var(AUDIT_DIR_ENV) else {
+        return;
+    };
+
+    let krate = std::env::var("CARGO_CRATE_NAME").unwrap_or_default();
+    let mut file = String::new();
+    let mut line = 0;
+    let mut column = 0;
+    let location = |span: proc_macro2::Span| {
+        let span = span.unwrap();
+        file = span.file();
+        line = span.line();
+        column = span.column();
+        (file.clone(), line)
+    };
+    let Ok(ManifestEntry {
+        container_name,
+        json,
+    }) = audit_manifest_entry(args.clone().into(), input.clone().into(), &krate, location)
+    else {
+        return;
+    };
+
+    // Structs with the same name can be declared in different modules of one file,
+    // so the position keeps their entries apart while sorting them in source order
+    let file_name = format!("{krate}.{file}.{line:06}.{column:04}.{container_name}.json").replace(
+        |c: char| !(c.is_alphanumeric() || c == '.' || c == '_' || c == '-'),
+        "_",
+    );
+    let path = Path::new(&dir).join(file_name);
+
+    let result = std::fs::create_dir_all(&dir)
+        .and_then(|()| clear_old_entries(Path::new(&dir), &krate))
+        .and_then(|()| std::fs::write(&path, json));
+    if let Err(error) = result {
+        Diagnostic::new(
+            proc_macro::Level::Warning,
+            format!(
+                "failed to write audit manifest entry to {}: {error}",
+                path.display()
+            ),
+        )
+        .emit();
+        return;
+    }
+
+    // Deleting the entry, e.g. by clearing the directory, makes cargo build the crate and write it again
+    if let Some(path) = path.to_str() {
+        proc_macro::tracked::path(path);
+    }
+}
+
+/// Remove the entries written by the previous build of `krate`, once per build
+fn clear_old_entries(dir: &Path, krate: &str) -> std::io::Result<()> {
+    let mut cleared = CLEARED_CRATES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if cleared.iter().any(|cleared| cleared == krate) {
+        return Ok(());
+    }
+    cleared.push(krate.to_string());
+
+    let prefix = format!("{krate}.");
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_old_entry = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| {
+                name.starts_with(&prefix)
+                    && Path::new(name)
+                        .extension()
+                        .is_some_and(|extension| extension == "json")
+            });
+        if is_old_entry {
+            std::fs::remove_file(path)?;
+        }
+    }
+    Ok(())
+}
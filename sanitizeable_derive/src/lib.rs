@@ -1,12 +1,10 @@
-#![feature(proc_macro_diagnostic)]
+#![feature(proc_macro_diagnostic, proc_macro_tracked_env, proc_macro_tracked_path)]
 #![deny(clippy::pedantic)]
 
-mod datatypes;
-mod state_machine;
-mod states;
-mod util;
+mod audit;
 
-use syn::parse_macro_input;
+use proc_macro::Diagnostic;
+use sanitizeable_derive_core::{expand_with_warnings, fallback, Expansion};
 
 /// Derive the Serializeable trait and create all required structs
 ///
@@ -31,14 +29,30 @@ use syn::parse_macro_input;
 /// - `#[container_name = "..."]`
 /// - `#[union_name = "..."]`
 ///
+/// If `sanitizeable` is only available through a re-export, pass its path using `#[sanitizeable(crate = "...")]`.
+/// All generated code refers to it and to `core`/`std` using absolute paths.
+///
 ///
 /// You can also apply attributes to only one of the variants by using
 /// - `#[public_attr::your_attribute]`
 /// - `#[private_attr::your_attribute]`
 ///
+/// The public variant has a `PhantomData` placeholder for every private field.
+/// These make it inherit auto traits like `Send` and the variance of the private fields.
+/// Pass e.g. `#[sanitizeable(phantom = "fn() -> _")]` to use `PhantomData<fn() -> T>` for a private field of type `T` instead,
+/// which implements all auto traits but keeps the variance.
+/// Attributes can be applied to only these placeholders by using `#[phantom_attr::your_attribute]`,
+/// e.g. `#[phantom_attr::serde(skip)]`.
+///
 ///
 /// Note that this works both on the whole struct as well as on specific fields
-/// You are however **not** able use the `cfg` attribute, since that would break internal layout guarantees.
+/// You are however **not** able use the `cfg`, `cfg_attr` or `repr` attributes, since those would break internal layout guarantees.
+///
+/// In the types of fields, `Self` and the name of your `struct` refer to the container type,
+/// so recursive structs like `#[private] replies: Vec<Self>` hold containers.
+/// Public fields can not hold the struct, e.g. in a `Vec` or through a reference, since the public variant
+/// would give access to the private fields of the nested values. Other types like `PhantomData<Self>` or
+/// a typed id `Id<Self>` refer to the container, so make sure they do not hold it either.
 ///
 /// To use the resulting types you need to import the `Sanitizeable` trait.
 /// You can then call the `public`, `public_mut`, `private`, `private_mut` and `into_private` methods on
@@ -48,10 +62,118 @@ use syn::parse_macro_input;
 ///
 /// There is not currenty a `into_public` method, since that is pretty difficult to do due to how `Drop` works.
 /// This functionality might be added in the future.
+///
+/// If every field is `Copy`, passing `copy` leaves out the `Drop` implementation of the container
+/// and implements `Clone` and `Copy` for the container and both variants, so do not derive these yourself.
+/// Generic containers can then only be created if the private variant is `Copy` for the given parameters.
+///
+/// The methods of `Sanitizeable` can not be called in constants. Passing `const_fn` adds an inherent `const fn new`
+/// to the container which does the same as `from_private`, as well as `const fn`s `public` and `private`,
+/// so containers can be created in `const`s and `static`s. These take precedence over the trait methods of the same name.
+///
+/// Passing `accessors` adds inherent `public`, `private` and `into_private` methods to the container,
+/// so `Sanitizeable` does not need to be imported to use it, and a getter for every public field,
+/// e.g. `user.name()` returning `&user.public().name`. Private fields do not get a getter.
+/// A public field can not be named like a method of `Sanitizeable` or an item added by another argument, e.g. `seal`,
+/// and `accessors` can not be combined with `graphql`, whose resolvers are already named like the fields.
+///
+///
+/// Passing `seal` (e.g. `#[sanitizeable(seal)]`, requires the `encryption` feature) additionally creates
+/// a `TestSealed` struct (name configurable with `#[sealed_name = "..."]`) which holds the public fields in the clear
+/// and all private fields as one encrypted `ciphertext`.
+/// It is created with `seal` on the container and turned back into one using `unseal`.
+/// The public fields are authenticated along with the ciphertext, so they need to implement `serde::Serialize`
+/// and a ciphertext can not be moved to a sealed struct with different public fields.
+/// Attributes can be applied to only the sealed variant by using `#[sealed_attr::your_attribute]`.
+///
+/// Passing `sign` (requires the `signing` feature) implements `CanonicalEncoding` for the public variant
+/// and adds a `sign_public` method to the container, which returns a `Signed` copy of the public variant.
+/// The signature only covers the public fields, which need to implement `serde::Serialize`
+/// and serialize to the same bytes whenever they are equal. Public fields mentioning `HashMap` or `HashSet`
+/// are therefore rejected; unordered collections hidden behind type aliases or other types are not detected.
+///
+/// Passing `erase` adds an `erase_private` method to the container, which resets all private fields in place
+/// and returns an `ErasureReport`. Fields are reset to their `Default` value unless they are annotated with
+/// `#[private(erase_with = "path::to::function")]`, which is an error without `erase`.
+///
+/// Passing `catalogue` adds a `FIELDS` constant to the container listing the name, type and privacy of every field.
+/// Fields can be given data classification labels and a retention period which show up there using
+/// `#[classify(PII, PCI, retention = "30d")]`. These labels are also part of the audit manifest described below,
+/// so `#[classify]` is reserved by `#[sanitizeable]` even without `catalogue`.
+///
+/// Passing `view_sql` on a struct with named fields adds a `create_public_view_sql` function to the container,
+/// which returns a SQL `CREATE VIEW` statement exposing only the public columns of a table.
+/// Fields stored in a column with a different name can be annotated with `#[column = "..."]`,
+/// which is also respected by the `sqlx` and `diesel` integrations. Without any of these, `#[column]` is left
+/// on the fields for other derives.
+///
+/// If the environment variable `SANITIZEABLE_AUDIT_DIR` is set during the build, a JSON manifest entry
+/// describing the struct is written to that directory. Use `sanitizeable::audit::merge_manifest` to combine them.
+/// Building a crate replaces all of its previous entries.
+///
+/// Passing `json_schema` (requires the `schemars` feature) derives `schemars::JsonSchema` for the public variant,
+/// skipping the placeholders for private fields. These are also skipped by serde, so the public variant
+/// serializes to what its schema describes. `private_json_schema` derives `JsonSchema` for the private variant.
+///
+/// Passing `typescript` adds a `typescript_definition` function to the public variant, which returns a TypeScript
+/// interface matching its JSON representation. Containers are referenced by the interface of their public variant
+/// (e.g. `AddressPublic` for a field of type `Address`), other types that are not known to the macro by their name.
+/// The placeholders for private fields serialize to `null` unless they are skipped with `#[phantom_attr::serde(skip)]`,
+/// and serde's `rename`, `rename_all` and `skip` attributes are taken into account.
+///
+/// Passing `openapi` (requires the `utoipa` feature and `utoipa` as a direct dependency) derives `utoipa::ToSchema`
+/// for the public variant only, skipping the placeholders for private fields in the schema and in serde.
+///
+/// Passing `graphql` (requires the `graphql` feature) derives `async_graphql::SimpleObject` for the public variant
+/// and implements `async_graphql::Object` for the container. The container only resolves private fields
+/// which have a guard, e.g. `#[private(graphql_guard = "RoleGuard::new(Role::Admin)")]`.
+///
+/// Passing `axum` (requires the `axum` feature) implements `IntoResponse` for the container,
+/// which responds with the public variant as JSON. See `sanitizeable::axum::Json` for the matching extractor.
+/// If the public variant derives `Serialize`, the placeholders for private fields are left out of the JSON.
+///
+/// Passing `sqlx` (requires the `sqlx` feature) implements `sqlx::FromRow` for the container and the public variant.
+/// The container also gets `PUBLIC_COLUMNS` and `PRIVATE_COLUMNS` constants, so queries for the public variant
+/// can avoid selecting private columns.
+///
+/// Passing `diesel_table = "crate::schema::users"` (requires the `diesel` feature) implements diesel's `Queryable`
+/// and `Selectable` for the container and both variants, with the public variant only selecting public columns.
+/// References to the private variant and the container also implement `Insertable`.
+/// The container and the private variant can be loaded from the whole table, e.g. `users::table.load::<User>()`,
+/// as long as the fields are declared in the same order as the columns in `table!`.
+/// Since diesel names columns with identifiers, `#[column = "..."]` has to be the name used in `table!`,
+/// not the one given to `#[sql_name = "..."]`.
+///
+/// Passing `valuable` (requires the `valuable` feature) implements `valuable::Valuable` for the public variant
+/// and the container, which only exposes the public fields. Use `sanitizeable::tracing::public` to record
+/// a container in a `tracing` event.
+///
+/// Passing `log` (requires the `log` feature) implements `log::kv::Source` for the container, which emits every
+/// public field as a separate key-value pair, and `log::kv::ToValue`, which captures the public variant through `serde`
+/// so the container can be logged directly, e.g. `log::info!(user; "login")`.
+/// This requires the public variant to implement `serde::Serialize`, e.g. through `#[public_attr::derive(Serialize)]`.
 #[proc_macro_attribute]
 pub fn sanitizeable(
     args: proc_macro::TokenStream,
     input: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
-    state_machine::run(parse_macro_input!(args), parse_macro_input!(input)).into()
+    match expand_with_warnings(args.clone().into(), input.clone().into()) {
+        Ok(Expansion { tokens, warnings }) => {
+            for warning in warnings {
+                Diagnostic::spanned(
+                    warning.span.unwrap(),
+                    proc_macro::Level::Warning,
+                    warning.message,
+                )
+                .emit();
+            }
+            audit::write_manifest_entry(&args, &input);
+            tokens.into()
+        }
+        Err(errors) => {
+            let mut tokens = fallback(args.into(), input.into());
+            tokens.extend(errors.iter().map(syn::Error::to_compile_error));
+            tokens.into()
+        }
+    }
 }
@@ -0,0 +1,109 @@
+use crate::Warning;
+use proc_macro2::Span;
+use std::{cell::RefCell, fmt::Display};
+use syn::{Attribute, Ident};
+
+/// Errors found in the input of the macro, which are collected so that all of them are reported at once
+#[derive(Default)]
+pub struct Errors {
+    errors: RefCell<Vec<syn::Error>>,
+    warnings: RefCell<Vec<Warning>>,
+}
+
+impl Errors {
+    pub fn push(&self, error: syn::Error) {
+        self.errors.borrow_mut().push(error);
+    }
+
+    pub fn spanned(&self, span: Span, message: impl Display) {
+        self.push(syn::Error::new(span, message));
+    }
+
+    /// Report something suspicious which does not stop the expansion
+    pub fn warn(&self, span: Span, message: impl Display) {
+        self.warnings.borrow_mut().push(Warning {
+            span,
+            message: message.to_string(),
+        });
+    }
+
+    /// Return all collected errors if there are any, and the warnings otherwise
+    pub fn finish(self) -> Result<Vec<Warning>, Vec<syn::Error>> {
+        let errors = self.errors.into_inner();
+        if errors.is_empty() {
+            Ok(self.warnings.into_inner())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+pub struct Attrs {
+    pub private_attrs: Vec<Attribute>,
+    pub public_attrs: Vec<Attribute>,
+    pub normal_attrs: Vec<Attribute>,
+    pub phantom_attrs: Option<Vec<Attribute>>,
+    pub sealed_attrs: Vec<Attribute>,
+}
+
+pub struct Names {
+    pub private_name: Ident,
+    pub public_name: Ident,
+    pub union_name: Ident,
+    pub container_name: Ident,
+    pub sealed_name: Ident,
+}
+
+pub struct Options {
+    /// The path of the `sanitizeable` crate used by the generated code
+    pub crate_path: syn::Path,
+    pub seal: Option<Span>,
+    pub sign: Option<Span>,
+    pub erase: Option<Span>,
+    pub json_schema: Option<Span>,
+    pub private_json_schema: Option<Span>,
+    pub typescript: Option<Span>,
+    pub openapi: Option<Span>,
+    pub graphql: Option<Span>,
+    pub axum: Option<Span>,
+    pub sqlx: Option<Span>,
+    pub valuable: Option<Span>,
+    pub log: Option<Span>,
+    pub copy: Option<Span>,
+    pub catalogue: Option<Span>,
+    pub view_sql: Option<Span>,
+    pub const_fn: Option<Span>,
+    pub accessors: Option<Span>,
+    pub diesel_table: Option<syn::LitStr>,
+    /// The parameter of the `PhantomData` placeholders, in which `_` stands for the type of the private field
+    pub phantom: Option<syn::Type>,
+}
+
+/// The data classification of a field from its `#[classify(...)]` attributes
+pub struct Classification {
+    pub labels: Vec<String>,
+    pub retention: Option<syn::LitStr>,
+}
+
+/// Arguments of a field's `#[private(...)]` attribute
+pub struct PrivateOptions {
+    pub erase_with: Option<syn::Path>,
+    pub graphql_guard: Option<syn::LitStr>,
+}
+
+pub struct Fields {
+    pub private_fields: Vec<syn::Field>,
+    pub public_fields: Vec<syn::Field>,
+    pub phantom_fields: Vec<syn::Field>,
+    pub sealed_fields: Vec<syn::Field>,
+    pub private_options: Vec<PrivateOptions>,
+    /// The classification of all fields, in the order of `private_fields`
+    pub classifications: Vec<Classification>,
+    /// The database column of all fields, in the order of `private_fields`
+    pub columns: Vec<String>,
+}
+
+pub struct FieldTokenStreams {
+    pub private_fields: proc_macro2::TokenStream,
+    pub public_fields: proc_macro2::TokenStream,
+}
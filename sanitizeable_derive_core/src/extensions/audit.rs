@@ -0,0 +1,165 @@
+use crate::{
+    datatypes::{Attrs, Classification, Errors, Fields, Names},
+    states::QuoteFields,
+    util::{
+        attr_is_classify, attr_is_column, attr_is_private, is_private, pretty_tokens,
+        reads_columns, split_attrs,
+    },
+};
+use std::fmt::Write;
+use syn::{Attribute, ItemStruct};
+
+/// Render the JSON manifest entry describing the struct, which is written to `$SANITIZEABLE_AUDIT_DIR`
+///
+/// Each struct gets its own file, which `sanitizeable::audit::merge_manifest` combines into a single report.
+/// The module path of the struct is not available to proc macros, so the entry contains its source file instead.
+pub fn manifest_entry(state: &QuoteFields, krate: &str, file: &str, line: usize) -> String {
+    let QuoteFields {
+        input: ItemStruct { fields, .. },
+        names:
+            Names {
+                private_name,
+                public_name,
+                container_name,
+                ..
+            },
+        struct_attrs,
+        options,
+        split_fields:
+            Fields {
+                public_fields,
+                classifications,
+                ..
+            },
+        ..
+    } = state;
+
+    let mut out = String::new();
+    out.push_str("{\n");
+    let _ = writeln!(
+        out,
+        "  \"type\": {},",
+        json_string(&container_name.to_string())
+    );
+    let _ = writeln!(
+        out,
+        "  \"public\": {},",
+        json_string(&public_name.to_string())
+    );
+    let _ = writeln!(
+        out,
+        "  \"private\": {},",
+        json_string(&private_name.to_string())
+    );
+    let _ = writeln!(out, "  \"crate\": {},", json_string(krate));
+    let _ = writeln!(out, "  \"file\": {},", json_string(file));
+    let _ = writeln!(out, "  \"line\": {line},");
+    let _ = writeln!(out, "  \"attributes\": {},", json_attrs(struct_attrs));
+    out.push_str("  \"fields\": [");
+
+    let (mut public_index, mut private_index) = (0, public_fields.len());
+    for (i, field) in fields.iter().enumerate() {
+        let private = is_private(field);
+        let index = if private {
+            private_index += 1;
+            private_index - 1
+        } else {
+            public_index += 1;
+            public_index - 1
+        };
+        let Classification { labels, retention } = &classifications[index];
+
+        let attrs = field
+            .attrs
+            .iter()
+            .filter(|attr| {
+                !attr_is_private(attr)
+                    && !attr_is_classify(attr)
+                    && (!attr_is_column(attr) || !reads_columns(options))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let name = field
+            .ident
+            .as_ref()
+            .map_or_else(|| i.to_string(), ToString::to_string);
+        let labels = labels
+            .iter()
+            .map(|label| json_string(label))
+            .collect::<Vec<_>>();
+        let retention = retention.as_ref().map_or_else(
+            || "null".to_string(),
+            |retention| json_string(&retention.value()),
+        );
+
+        out.push_str(if i == 0 { "\n" } else { ",\n" });
+        out.push_str("    {\n");
+        let _ = writeln!(out, "      \"name\": {},", json_string(&name));
+        let _ = writeln!(
+            out,
+            "      \"type\": {},",
+            json_string(&pretty_tokens(&field.ty))
+        );
+        let _ = writeln!(
+            out,
+            "      \"privacy\": {},",
+            json_string(if private { "private" } else { "public" })
+        );
+        let _ = writeln!(out, "      \"labels\": [{}],", labels.join(", "));
+        let _ = writeln!(out, "      \"retention\": {retention},");
+        let _ = writeln!(
+            out,
+            "      \"attributes\": {}",
+            json_attrs(&split_attrs(&attrs, &Errors::default()))
+        );
+        out.push_str("    }");
+    }
+
+    out.push_str("\n  ]\n}\n");
+    out
+}
+
+/// Render the attributes routed by `split_attrs` as a JSON object
+fn json_attrs(attrs: &Attrs) -> String {
+    let list = |attrs: &[Attribute]| {
+        let attrs = attrs
+            .iter()
+            .map(|attr| {
+                let path = &attr.path;
+                let tokens = &attr.tokens;
+                json_string(&pretty_tokens(&quote::quote!(#path #tokens)))
+            })
+            .collect::<Vec<_>>();
+        format!("[{}]", attrs.join(", "))
+    };
+
+    format!(
+        "{{ \"normal\": {}, \"public\": {}, \"private\": {}, \"phantom\": {}, \"sealed\": {} }}",
+        list(&attrs.normal_attrs),
+        list(&attrs.public_attrs),
+        list(&attrs.private_attrs),
+        list(attrs.phantom_attrs.as_deref().unwrap_or_default()),
+        list(&attrs.sealed_attrs),
+    )
+}
+
+fn json_string(string: &str) -> String {
+    let mut out = String::with_capacity(string.len() + 2);
+    out.push('"');
+    for c in string.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,46 @@
+use crate::{datatypes::Names, states::QuoteFields, util::extend_where_clause};
+use quote::quote;
+use syn::{parse_quote, ItemStruct};
+
+/// Quote an `axum::response::IntoResponse` implementation for the container,
+/// which renders the public variant as JSON
+pub fn quote_axum(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input: ItemStruct { generics, .. },
+        names: Names {
+            public_name,
+            container_name,
+            ..
+        },
+        options,
+        errors,
+        ..
+    } = state;
+    let krate = &options.crate_path;
+
+    let Some(span) = options.axum else {
+        return proc_macro2::TokenStream::new();
+    };
+
+    if !errors.require_feature(span, "axum", "axum", cfg!(feature = "axum")) {
+        return proc_macro2::TokenStream::new();
+    }
+
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let where_clause = extend_where_clause(
+        generics,
+        [parse_quote!(#public_name #ty_generics: #krate::__private::serde::Serialize)],
+    );
+
+    quote! {
+        impl #impl_generics #krate::__private::axum::response::IntoResponse
+            for #container_name #ty_generics #where_clause
+        {
+            fn into_response(self) -> #krate::__private::axum::response::Response {
+                #krate::__private::axum::response::IntoResponse::into_response(
+                    #krate::axum::Json(self),
+                )
+            }
+        }
+    }
+}
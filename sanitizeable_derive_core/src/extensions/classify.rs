@@ -0,0 +1,72 @@
+use crate::{
+    datatypes::{Classification, Fields, Names},
+    states::QuoteFields,
+    util::{members, pretty_tokens},
+};
+use quote::quote;
+use syn::{ItemStruct, Member};
+
+/// Quote the `FIELDS` constant of the container, which describes every field in the order of the private variant
+pub fn quote_catalogue(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input: ItemStruct { vis, generics, .. },
+        names: Names { container_name, .. },
+        split_fields:
+            Fields {
+                private_fields,
+                public_fields,
+                classifications,
+                ..
+            },
+        options,
+        ..
+    } = state;
+    let krate = &options.crate_path;
+
+    if options.catalogue.is_none() {
+        return proc_macro2::TokenStream::new();
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let infos = members(private_fields)
+        .into_iter()
+        .zip(private_fields)
+        .zip(classifications)
+        .enumerate()
+        .map(|(i, ((member, field), classification))| {
+            let name = match member {
+                Member::Named(ident) => ident.to_string(),
+                Member::Unnamed(index) => index.index.to_string(),
+            };
+            let ty = pretty_tokens(&field.ty);
+            let privacy = if i < public_fields.len() {
+                quote! { #krate::Privacy::Public }
+            } else {
+                quote! { #krate::Privacy::Private }
+            };
+            let Classification { labels, retention } = classification;
+            let retention = if let Some(retention) = retention {
+                quote! { ::core::option::Option::Some(#retention) }
+            } else {
+                quote! { ::core::option::Option::None }
+            };
+
+            quote! {
+                #krate::FieldInfo {
+                    name: #name,
+                    type_name: #ty,
+                    privacy: #privacy,
+                    labels: &[#(#labels),*],
+                    retention: #retention,
+                }
+            }
+        });
+
+    quote! {
+        impl #impl_generics #container_name #ty_generics #where_clause {
+            /// A description of all fields, including their privacy and data classification
+            #vis const FIELDS: &'static [#krate::FieldInfo] = &[#(#infos),*];
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{
+    datatypes::{Names, Options},
+    states::QuoteFields,
+    util::extend_where_clause,
+};
+use quote::quote;
+use syn::{parse_quote, Generics, ItemStruct, WherePredicate};
+
+/// Derive `Clone` and `Copy` for both variants, which fails to compile if any field is not `Copy`
+pub fn struct_attrs(options: &Options) -> proc_macro2::TokenStream {
+    if options.copy.is_none() {
+        return proc_macro2::TokenStream::new();
+    }
+
+    quote! {
+        #[derive(::core::clone::Clone, ::core::marker::Copy)]
+    }
+}
+
+/// Quote `Clone` and `Copy` implementations for the union and the container
+///
+/// The container does not implement `Drop` in this mode, since none of the fields need to be dropped.
+pub fn quote_copy(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input: ItemStruct { generics, .. },
+        names:
+            Names {
+                private_name,
+                public_name,
+                union_name,
+                container_name,
+                ..
+            },
+        options,
+        ..
+    } = state;
+
+    if options.copy.is_none() {
+        return proc_macro2::TokenStream::new();
+    }
+
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let where_clause = extend_where_clause(
+        generics,
+        [
+            parse_quote!(#private_name #ty_generics: ::core::marker::Copy),
+            parse_quote!(#public_name #ty_generics: ::core::marker::Copy),
+        ],
+    );
+
+    quote! {
+        impl #impl_generics ::core::clone::Clone for #union_name #ty_generics #where_clause {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl #impl_generics ::core::marker::Copy for #union_name #ty_generics #where_clause {}
+
+        impl #impl_generics ::core::clone::Clone for #container_name #ty_generics #where_clause {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl #impl_generics ::core::marker::Copy for #container_name #ty_generics #where_clause {}
+    }
+}
+
+/// Require the private variant to be `Copy` in every impl which uses the where clause of `input`
+///
+/// The container never drops its fields in this mode. The derived `Copy` is bounded by the generic parameters,
+/// so without this e.g. a `Pair<T>` could be created with a `T` that needs to be dropped.
+/// Containers without generics already fail to compile if a field is not `Copy`.
+pub fn require_copy(input: &mut ItemStruct, names: &Names, options: &Options) {
+    if let Some(predicate) = copy_predicate(&input.generics, names, options) {
+        input
+            .generics
+            .make_where_clause()
+            .predicates
+            .push(predicate);
+    }
+}
+
+/// A where clause with only the bound added by `require_copy`,
+/// for the impls which do not repeat the where clause of the struct
+pub fn copy_where_clause(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input: ItemStruct { generics, .. },
+        names,
+        options,
+        ..
+    } = state;
+
+    copy_predicate(generics, names, options)
+        .map(|predicate| quote!(where #predicate))
+        .unwrap_or_default()
+}
+
+fn copy_predicate(generics: &Generics, names: &Names, options: &Options) -> Option<WherePredicate> {
+    if options.copy.is_none() || generics.params.is_empty() {
+        return None;
+    }
+
+    let private_name = &names.private_name;
+    let (_, ty_generics, _) = generics.split_for_impl();
+    Some(parse_quote!(#private_name #ty_generics: ::core::marker::Copy))
+}
@@ -0,0 +1,322 @@
+use crate::{
+    datatypes::{Errors, Fields, Names},
+    states::QuoteFields,
+    util::{extend_generics, extend_where_clause, phantom_members},
+};
+use quote::quote;
+use syn::{ext::IdentExt, parse::Parser, parse_quote, Field, Generics, Ident, ItemStruct};
+
+/// The paths of the diesel `table!` columns of `fields`, or `None` if a column is not a valid identifier
+fn column_paths(
+    table: &syn::Path,
+    fields: &[Field],
+    columns: &[String],
+    errors: &Errors,
+) -> Option<Vec<proc_macro2::TokenStream>> {
+    fields
+        .iter()
+        .zip(columns)
+        .map(|(field, column)| {
+            let span = field.ident.as_ref().unwrap().span();
+            let column = match (
+                syn::parse_str::<Ident>(column),
+                Ident::parse_any.parse_str(column),
+            ) {
+                (Ok(mut ident), _) => {
+                    ident.set_span(span);
+                    ident
+                }
+                // Keywords like `type` have to be raw identifiers in `table!`
+                (Err(_), Ok(_)) if !["self", "super", "crate", "Self"].contains(&column.as_str()) => {
+                    Ident::new_raw(column, span)
+                }
+                _ => {
+                    errors.spanned(
+                        span,
+                        format!("`{column}` is not a valid identifier, so it can not name a column of a diesel `table!`"),
+                    );
+                    return None;
+                }
+            };
+            Some(quote!(#table::#column))
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// `fields` and their `columns` in the order in which they are declared in `input`
+///
+/// The private variant puts the public fields first, but a `Queryable` row has to follow the order of the columns
+/// in `table!`, which usually matches the declaration order, so e.g. `users::table.load::<User>()` works.
+fn in_source_order(
+    input: &syn::Fields,
+    fields: &[Field],
+    columns: &[String],
+) -> (Vec<Field>, Vec<String>) {
+    let position = |field: &Field| input.iter().position(|input| input.ident == field.ident);
+    let mut fields = fields
+        .iter()
+        .cloned()
+        .zip(columns.iter().cloned())
+        .collect::<Vec<_>>();
+    fields.sort_by_key(|(field, _)| position(field));
+    fields.into_iter().unzip()
+}
+
+/// Quote `Selectable` and `Queryable` for a struct made up of exactly `fields`
+fn quote_selectable(
+    krate: &syn::Path,
+    generics: &Generics,
+    ty: &proc_macro2::TokenStream,
+    fields: &[Field],
+    columns: &[proc_macro2::TokenStream],
+    phantom: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let db_generics = extend_generics(
+        generics,
+        [parse_quote!(__DB: #krate::__private::diesel::backend::Backend)],
+    );
+    let (impl_generics, _, where_clause) = db_generics.split_for_impl();
+
+    let row_generics = extend_generics(&db_generics, [parse_quote!(__ST)]);
+    let (row_impl_generics, _, _) = row_generics.split_for_impl();
+
+    let types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let idents = fields.iter().map(|field| &field.ident).collect::<Vec<_>>();
+
+    let row_where_clause = extend_where_clause(
+        generics,
+        [parse_quote! {
+            (#(#types,)*): #krate::__private::diesel::deserialize::FromStaticSqlRow<__ST, __DB>
+        }],
+    );
+
+    quote! {
+        impl #impl_generics #krate::__private::diesel::Selectable<__DB> for #ty #where_clause {
+            type SelectExpression = (#(#columns,)*);
+
+            fn construct_selection() -> Self::SelectExpression {
+                (#(#columns,)*)
+            }
+        }
+
+        impl #row_impl_generics #krate::__private::diesel::Queryable<__ST, __DB> for #ty
+            #row_where_clause
+        {
+            type Row = (#(#types,)*);
+
+            fn build((#(#idents,)*): Self::Row) -> #krate::__private::diesel::deserialize::Result<Self> {
+                ::core::result::Result::Ok(Self {
+                    #(#idents,)*
+                    #phantom
+                })
+            }
+        }
+    }
+}
+
+/// Quote `Insertable` for references to the private variant
+fn quote_insertable(
+    krate: &syn::Path,
+    table: &syn::Path,
+    generics: &Generics,
+    ty: &proc_macro2::TokenStream,
+    fields: &[Field],
+    columns: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    let ref_generics = extend_generics(generics, [parse_quote!('__a)]);
+    let (impl_generics, _, where_clause) = ref_generics.split_for_impl();
+
+    let types = fields.iter().map(|field| &field.ty);
+    let idents = fields.iter().map(|field| &field.ident);
+
+    quote! {
+        impl #impl_generics #krate::__private::diesel::Insertable<#table::table> for &'__a #ty
+            #where_clause
+        {
+            type Values = <(#(#krate::__private::diesel::dsl::Eq<#columns, &'__a #types>,)*)
+                as #krate::__private::diesel::Insertable<#table::table>>::Values;
+
+            fn values(self) -> Self::Values {
+                #krate::__private::diesel::Insertable::values((
+                    #(#krate::__private::diesel::ExpressionMethods::eq(#columns, &self.#idents),)*
+                ))
+            }
+        }
+    }
+}
+
+/// Quote the diesel traits of the container, which all delegate to the private variant
+fn quote_container(
+    krate: &syn::Path,
+    table: &syn::Path,
+    generics: &Generics,
+    container: &proc_macro2::TokenStream,
+    private: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let db_generics = extend_generics(
+        generics,
+        [parse_quote!(__DB: #krate::__private::diesel::backend::Backend)],
+    );
+    let (impl_generics, _, where_clause) = db_generics.split_for_impl();
+
+    let row_generics = extend_generics(&db_generics, [parse_quote!(__ST)]);
+    let (row_impl_generics, _, _) = row_generics.split_for_impl();
+    let row_where_clause = extend_where_clause(
+        generics,
+        [parse_quote!(#private: #krate::__private::diesel::Queryable<__ST, __DB>)],
+    );
+
+    let ref_generics = extend_generics(generics, [parse_quote!('__a)]);
+    let (ref_impl_generics, _, _) = ref_generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #krate::__private::diesel::Selectable<__DB> for #container #where_clause {
+            type SelectExpression = <#private as #krate::__private::diesel::Selectable<__DB>>::SelectExpression;
+
+            fn construct_selection() -> Self::SelectExpression {
+                <#private as #krate::__private::diesel::Selectable<__DB>>::construct_selection()
+            }
+        }
+
+        impl #row_impl_generics #krate::__private::diesel::Queryable<__ST, __DB> for #container
+            #row_where_clause
+        {
+            type Row = <#private as #krate::__private::diesel::Queryable<__ST, __DB>>::Row;
+
+            fn build(row: Self::Row) -> #krate::__private::diesel::deserialize::Result<Self> {
+                <#private as #krate::__private::diesel::Queryable<__ST, __DB>>::build(row)
+                    .map(#krate::Sanitizeable::from_private)
+            }
+        }
+
+        impl #ref_impl_generics #krate::__private::diesel::Insertable<#table::table> for &'__a #container
+            #where_clause
+        {
+            type Values = <&'__a #private as #krate::__private::diesel::Insertable<#table::table>>::Values;
+
+            fn values(self) -> Self::Values {
+                #krate::__private::diesel::Insertable::values(
+                    #krate::Sanitizeable::private(self),
+                )
+            }
+        }
+    }
+}
+
+/// Quote the diesel integration for the `table!` named by `diesel_table = "..."`
+///
+/// The public variant only selects the public columns, while the private variant and the container select all columns.
+/// Only the private variant and the container can be inserted.
+pub fn quote_diesel(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input:
+            ItemStruct {
+                generics,
+                fields: input_fields,
+                ..
+            },
+        names:
+            Names {
+                public_name,
+                private_name,
+                container_name,
+                ..
+            },
+        options,
+        errors,
+        split_fields:
+            Fields {
+                private_fields,
+                public_fields,
+                phantom_fields,
+                columns,
+                ..
+            },
+        ..
+    } = state;
+    let krate = &options.crate_path;
+
+    let Some(table_name) = &options.diesel_table else {
+        return proc_macro2::TokenStream::new();
+    };
+
+    if !errors.require_feature(
+        table_name.span(),
+        "diesel_table",
+        "diesel",
+        cfg!(feature = "diesel"),
+    ) {
+        return proc_macro2::TokenStream::new();
+    }
+
+    let columns_of_public = &columns[..public_fields.len()];
+    let table: syn::Path = match table_name.parse() {
+        Ok(path) => path,
+        Err(error) => {
+            errors.spanned(
+                table_name.span(),
+                format!("expected a path to a diesel `table!`: {error}"),
+            );
+            return proc_macro2::TokenStream::new();
+        }
+    };
+
+    if private_fields.iter().any(|field| field.ident.is_none()) {
+        errors.spanned(
+            table_name.span(),
+            "`diesel_table` can only be used on structs with named fields",
+        );
+        return proc_macro2::TokenStream::new();
+    }
+
+    let (private_fields, columns) = in_source_order(input_fields, private_fields, columns);
+    // The public columns are a subset of the private ones, so they are only checked once
+    let Some(private_columns) = column_paths(&table, &private_fields, &columns, errors) else {
+        return proc_macro2::TokenStream::new();
+    };
+    let public_columns = column_paths(&table, public_fields, columns_of_public, errors)
+        .expect("the private columns include the public columns");
+
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let public = quote!(#public_name #ty_generics);
+    let private = quote!(#private_name #ty_generics);
+    let container = quote!(#container_name #ty_generics);
+
+    let phantom_members = phantom_members(phantom_fields, public_fields.len());
+    let phantom = quote!(#(#phantom_members: ::core::marker::PhantomData,)*);
+
+    let public_impls = quote_selectable(
+        krate,
+        generics,
+        &public,
+        public_fields,
+        &public_columns,
+        &phantom,
+    );
+    let private_impls = quote_selectable(
+        krate,
+        generics,
+        &private,
+        &private_fields,
+        &private_columns,
+        &proc_macro2::TokenStream::new(),
+    );
+    let insertable = quote_insertable(
+        krate,
+        &table,
+        generics,
+        &private,
+        &private_fields,
+        &private_columns,
+    );
+    let container_impls = quote_container(krate, &table, generics, &container, &private);
+
+    quote! {
+        #public_impls
+        #private_impls
+        #insertable
+        #container_impls
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    datatypes::{Fields, Names},
+    states::QuoteFields,
+    util::members,
+};
+use quote::quote;
+use syn::{ItemStruct, Member};
+
+/// Quote the `erase_private` method, which resets all private fields in place
+///
+/// Every private field is set to the result of its `#[private(erase_with = "...")]` function,
+/// or to its `Default` value if it does not have one.
+pub fn quote_erase(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input: ItemStruct { vis, generics, .. },
+        names: Names { container_name, .. },
+        options,
+        errors,
+        split_fields:
+            Fields {
+                private_fields,
+                public_fields,
+                private_options,
+                ..
+            },
+        ..
+    } = state;
+    let krate = &options.crate_path;
+
+    if options.erase.is_none() {
+        for erase_with in private_options
+            .iter()
+            .filter_map(|options| options.erase_with.as_ref())
+        {
+            errors.push(syn::Error::new_spanned(
+                erase_with,
+                "`erase_with` requires the `erase` argument of `#[sanitizeable(...)]`",
+            ));
+        }
+        return proc_macro2::TokenStream::new();
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let private_members = members(private_fields)[public_fields.len()..].to_vec();
+    let private_only_fields = &private_fields[public_fields.len()..];
+
+    let field_names = private_members.iter().map(|member| match member {
+        Member::Named(ident) => ident.to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    });
+
+    let mut default_types = Vec::new();
+    let values = private_only_fields
+        .iter()
+        .zip(private_options)
+        .map(|(field, options)| {
+            if let Some(erase_with) = &options.erase_with {
+                quote! { #erase_with() }
+            } else {
+                default_types.push(&field.ty);
+                quote! { ::core::default::Default::default() }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    quote! {
+        impl #impl_generics #container_name #ty_generics #where_clause {
+            /// Reset all private fields in place, keeping the public fields intact
+            ///
+            /// The previous values are dropped normally, they are **not** overwritten in memory.
+            #vis fn erase_private(&mut self) -> #krate::ErasureReport
+            where
+                #(#default_types: ::core::default::Default,)*
+            {
+                let private = #krate::Sanitizeable::private_mut(self);
+                #(private.#private_members = #values;)*
+
+                #krate::ErasureReport {
+                    erased: &[#(#field_names),*],
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use super::reexport_path;
+use crate::{
+    datatypes::{Errors, Fields, Names, Options},
+    states::QuoteFields,
+};
+use quote::quote;
+use syn::{parse_quote, Attribute, Field, ItemStruct};
+
+/// Derive `async_graphql::SimpleObject` for the public variant
+pub fn public_struct_attrs(options: &Options, errors: &Errors) -> proc_macro2::TokenStream {
+    let Some(span) = options.graphql else {
+        return proc_macro2::TokenStream::new();
+    };
+
+    if !errors.require_feature(span, "graphql", "graphql", cfg!(feature = "graphql")) {
+        return proc_macro2::TokenStream::new();
+    }
+
+    let krate = &options.crate_path;
+    let graphql_path = reexport_path(krate, "async_graphql");
+
+    quote! {
+        #[derive(#krate::__private::async_graphql::SimpleObject)]
+        #[graphql(crate = #graphql_path)]
+    }
+}
+
+/// The placeholders for private fields are not part of the GraphQL object
+pub fn phantom_field_attrs(options: &Options) -> Vec<Attribute> {
+    if options.graphql.is_some() && cfg!(feature = "graphql") {
+        vec![parse_quote!(#[graphql(skip)])]
+    } else {
+        Vec::new()
+    }
+}
+
+fn doc_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path.is_ident("doc"))
+}
+
+/// Quote an `async_graphql::Object` implementation for the container
+///
+/// It resolves all public fields and those private fields that have a `#[private(graphql_guard = "...")]`.
+/// Private fields without a guard are never exposed.
+pub fn quote_graphql(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input: ItemStruct {
+            attrs, generics, ..
+        },
+        names: Names { container_name, .. },
+        options,
+        errors,
+        split_fields:
+            Fields {
+                private_fields,
+                public_fields,
+                private_options,
+                ..
+            },
+        ..
+    } = state;
+
+    let guards = private_options
+        .iter()
+        .filter_map(|options| options.graphql_guard.as_ref());
+
+    let Some(span) = options.graphql else {
+        for guard in guards {
+            errors.spanned(
+                guard.span(),
+                "`graphql_guard` requires the `graphql` argument of `#[sanitizeable(...)]`",
+            );
+        }
+        return proc_macro2::TokenStream::new();
+    };
+
+    if !cfg!(feature = "graphql") {
+        return proc_macro2::TokenStream::new();
+    }
+
+    if private_fields.iter().any(|field| field.ident.is_none()) {
+        errors.spanned(
+            span,
+            "`graphql` can only be used on structs with named fields",
+        );
+        return proc_macro2::TokenStream::new();
+    }
+
+    let krate = &options.crate_path;
+    let graphql_path = reexport_path(krate, "async_graphql");
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let public_resolvers = public_fields.iter().map(|field| {
+        let Field { ident, ty, .. } = field;
+        let docs = doc_attrs(&field.attrs);
+        quote! {
+            #(#docs)*
+            async fn #ident(&self) -> &#ty {
+                &#krate::Sanitizeable::public(self).#ident
+            }
+        }
+    });
+
+    let private_resolvers = private_fields[public_fields.len()..]
+        .iter()
+        .zip(private_options)
+        .filter_map(|(field, options)| {
+            let guard = options.graphql_guard.as_ref()?;
+            let Field { ident, ty, .. } = field;
+            let docs = doc_attrs(&field.attrs);
+            Some(quote! {
+                #(#docs)*
+                #[graphql(guard = #guard)]
+                async fn #ident(&self) -> &#ty {
+                    &#krate::Sanitizeable::private(self).#ident
+                }
+            })
+        });
+
+    let docs = doc_attrs(attrs);
+
+    quote! {
+        #(#docs)*
+        #[#krate::__private::async_graphql::Object(crate = #graphql_path)]
+        impl #impl_generics #container_name #ty_generics #where_clause {
+            #(#public_resolvers)*
+            #(#private_resolvers)*
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{
+    datatypes::{Fields, Names, Options},
+    states::QuoteFields,
+};
+use proc_macro2::Span;
+use quote::quote;
+use syn::ItemStruct;
+
+/// Quote inherent `const fn`s to create the container and to access both variants,
+/// since the methods of `Sanitizeable` can not be called in constants
+pub fn quote_const_fn(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input: ItemStruct { vis, generics, .. },
+        names:
+            Names {
+                private_name,
+                public_name,
+                union_name,
+                container_name,
+                ..
+            },
+        options,
+        ..
+    } = state;
+
+    if options.const_fn.is_none() {
+        return proc_macro2::TokenStream::new();
+    }
+
+    // Like the `Sanitizeable` implementation, this does not repeat the where clause of the struct
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let private = quote!(#private_name #ty_generics);
+    let public = quote!(#public_name #ty_generics);
+    let where_clause = super::copy_where_clause(state);
+
+    quote! {
+        impl #impl_generics #container_name #ty_generics #where_clause {
+            /// Create the container from the private variant, like `Sanitizeable::from_private`
+            #vis const fn new(private: #private) -> Self {
+                Self(#union_name {
+                    __sanitizeable_private: ::core::mem::ManuallyDrop::new(private),
+                })
+            }
+
+            /// Access the public variant, like `Sanitizeable::public`
+            ///
+            /// Safety:
+            /// see `Sanitizeable::public`, `ManuallyDrop` is `#[repr(transparent)]`
+            #vis const fn public(&self) -> &#public {
+                let () = Self::__SANITIZEABLE_LAYOUT;
+                unsafe {
+                    &*(&self.0.__sanitizeable_public as *const ::core::mem::ManuallyDrop<#public>
+                        as *const #public)
+                }
+            }
+
+            /// Access the private variant, like `Sanitizeable::private`
+            ///
+            /// Safety:
+            /// see `Sanitizeable::private`, `ManuallyDrop` is `#[repr(transparent)]`
+            #vis const fn private(&self) -> &#private {
+                unsafe {
+                    &*(&self.0.__sanitizeable_private as *const ::core::mem::ManuallyDrop<#private>
+                        as *const #private)
+                }
+            }
+        }
+    }
+}
+
+/// Quote inherent methods forwarding to `Sanitizeable` and a getter for every public field,
+/// so the trait does not have to be imported to use the container
+pub fn quote_accessors(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input: ItemStruct { vis, generics, .. },
+        names:
+            Names {
+                private_name,
+                public_name,
+                container_name,
+                ..
+            },
+        options,
+        split_fields: Fields { public_fields, .. },
+        errors,
+        ..
+    } = state;
+    let krate = &options.crate_path;
+
+    if options.accessors.is_none() {
+        return proc_macro2::TokenStream::new();
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let private = quote!(#private_name #ty_generics);
+    let public = quote!(#public_name #ty_generics);
+
+    // `const_fn` already adds `public` and `private`
+    let references = if options.const_fn.is_none() {
+        quote! {
+            /// Access the public variant, see `Sanitizeable::public`
+            #vis fn public(&self) -> &#public {
+                #krate::Sanitizeable::public(self)
+            }
+
+            /// Access the private variant, see `Sanitizeable::private`
+            #vis fn private(&self) -> &#private {
+                #krate::Sanitizeable::private(self)
+            }
+        }
+    } else {
+        proc_macro2::TokenStream::new()
+    };
+
+    if let Some(graphql) = options.graphql {
+        errors.spanned(
+            graphql,
+            "`accessors` can not be combined with `graphql`, since the GraphQL resolvers are methods of the container named like the fields",
+        );
+        return proc_macro2::TokenStream::new();
+    }
+
+    let reserved = reserved_names(options);
+    let getters = public_fields.iter().filter_map(|field| {
+        let ident = field.ident.as_ref()?;
+        if reserved.iter().any(|name| ident == name) {
+            errors.spanned(
+                ident.span(),
+                format!("`accessors` can not add a getter for `{ident}`, since the container already has an item with that name"),
+            );
+            return None;
+        }
+
+        let field_vis = &field.vis;
+        let ty = &field.ty;
+        let doc = format!("The public field `{ident}`");
+        Some(quote! {
+            #[doc = #doc]
+            #field_vis fn #ident(&self) -> &#ty {
+                &#krate::Sanitizeable::public(self).#ident
+            }
+        })
+    });
+
+    quote! {
+        impl #impl_generics #container_name #ty_generics #where_clause {
+            #references
+
+            /// Take out the private variant, see `Sanitizeable::into_private`
+            #vis fn into_private(self) -> #private {
+                #krate::Sanitizeable::into_private(self)
+            }
+
+            #(#getters)*
+        }
+    }
+}
+
+/// The names of the items every container has, together with the ones added by the enabled extensions
+///
+/// Inherent getters with these names would either conflict with the items or shadow the methods of `Sanitizeable`.
+fn reserved_names(options: &Options) -> Vec<&'static str> {
+    let mut reserved = vec![
+        "public",
+        "public_mut",
+        "private",
+        "private_mut",
+        "from_private",
+        "into_private",
+        "__SANITIZEABLE_LAYOUT",
+    ];
+
+    let extensions: [(Option<Span>, &[&str]); 7] = [
+        (options.const_fn, &["new"]),
+        (options.seal, &["seal"]),
+        (options.sign, &["sign_public"]),
+        (options.erase, &["erase_private"]),
+        (options.catalogue, &["FIELDS"]),
+        (options.view_sql, &["create_public_view_sql"]),
+        (options.sqlx, &["PUBLIC_COLUMNS", "PRIVATE_COLUMNS"]),
+    ];
+    for (enabled, names) in extensions {
+        if enabled.is_some() {
+            reserved.extend(names);
+        }
+    }
+    reserved
+}
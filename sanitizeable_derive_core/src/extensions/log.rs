@@ -0,0 +1,83 @@
+use crate::{
+    datatypes::{Fields, Names},
+    states::QuoteFields,
+    util::{extend_where_clause, members},
+};
+use quote::quote;
+use syn::{ext::IdentExt, parse_quote, ItemStruct, Member};
+
+/// Quote `log::kv::Source` for the container, which visits every public field as a separate key-value pair,
+/// and `log::kv::ToValue`, which captures the public variant through `serde`
+pub fn quote_log(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input: ItemStruct { generics, .. },
+        names: Names {
+            public_name,
+            container_name,
+            ..
+        },
+        options,
+        errors,
+        split_fields: Fields { public_fields, .. },
+        ..
+    } = state;
+    let krate = &options.crate_path;
+
+    let Some(span) = options.log else {
+        return proc_macro2::TokenStream::new();
+    };
+
+    if !errors.require_feature(span, "log", "log", cfg!(feature = "log")) {
+        return proc_macro2::TokenStream::new();
+    }
+
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+
+    let public_members = members(public_fields);
+    let keys = public_members.iter().map(|member| match member {
+        Member::Named(ident) => ident.unraw().to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    });
+
+    let source_where_clause = extend_where_clause(
+        generics,
+        public_fields.iter().map(|field| -> syn::WherePredicate {
+            let ty = &field.ty;
+            parse_quote!(#ty: #krate::__private::log::kv::ToValue)
+        }),
+    );
+    let value_where_clause = extend_where_clause(
+        generics,
+        [parse_quote!(#public_name #ty_generics: #krate::__private::serde::Serialize)],
+    );
+
+    quote! {
+        impl #impl_generics #krate::__private::log::kv::Source for #container_name #ty_generics
+            #source_where_clause
+        {
+            fn visit<'__kvs>(
+                &'__kvs self,
+                visitor: &mut dyn #krate::__private::log::kv::VisitSource<'__kvs>,
+            ) -> ::core::result::Result<(), #krate::__private::log::kv::Error> {
+                let public = #krate::Sanitizeable::public(self);
+                #(
+                    visitor.visit_pair(
+                        #krate::__private::log::kv::Key::from_str(#keys),
+                        #krate::__private::log::kv::ToValue::to_value(&public.#public_members),
+                    )?;
+                )*
+                ::core::result::Result::Ok(())
+            }
+        }
+
+        impl #impl_generics #krate::__private::log::kv::ToValue for #container_name #ty_generics
+            #value_where_clause
+        {
+            fn to_value(&self) -> #krate::__private::log::kv::Value<'_> {
+                #krate::__private::log::kv::Value::from_serde(
+                    #krate::Sanitizeable::public(self),
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{
+    datatypes::{Attrs, Errors, Options},
+    states::QuoteFields,
+    util::{derives_serde, pretty_tokens, skips_serde},
+};
+use proc_macro2::Span;
+use quote::quote;
+use syn::parse_quote;
+
+pub mod audit;
+mod axum;
+mod classify;
+mod copy;
+mod diesel;
+mod erase;
+mod graphql;
+mod inherent;
+mod log;
+mod openapi;
+mod schema;
+mod seal;
+mod sign;
+mod sqlx;
+mod typescript;
+mod valuable;
+mod view;
+
+pub use copy::{copy_where_clause, require_copy};
+
+/// Quote all optional items enabled through the arguments of `#[sanitizeable(...)]`
+pub fn quote_extensions(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let seal = seal::quote_seal(state);
+    let sign = sign::quote_sign(state);
+    let erase = erase::quote_erase(state);
+    let catalogue = classify::quote_catalogue(state);
+    let typescript = typescript::quote_typescript(state);
+    let graphql = graphql::quote_graphql(state);
+    let axum = axum::quote_axum(state);
+    let sqlx = sqlx::quote_sqlx(state);
+    let diesel = diesel::quote_diesel(state);
+    let view_sql = view::quote_view_sql(state);
+    let valuable = valuable::quote_valuable(state);
+    let log = log::quote_log(state);
+    let copy = copy::quote_copy(state);
+    let const_fn = inherent::quote_const_fn(state);
+    let accessors = inherent::quote_accessors(state);
+
+    quote! {
+        #catalogue
+        #view_sql
+        #seal
+        #sign
+        #erase
+        #typescript
+        #graphql
+        #axum
+        #sqlx
+        #diesel
+        #valuable
+        #log
+        #copy
+        #const_fn
+        #accessors
+    }
+}
+
+/// Additional attributes for the public variant
+pub fn public_struct_attrs(options: &Options, errors: &Errors) -> proc_macro2::TokenStream {
+    let schema = schema::public_struct_attrs(options, errors);
+    let openapi = openapi::public_struct_attrs(options, errors);
+    let graphql = graphql::public_struct_attrs(options, errors);
+    let copy = copy::struct_attrs(options);
+
+    quote! {
+        #schema
+        #openapi
+        #graphql
+        #copy
+    }
+}
+
+/// Additional attributes for the private variant
+pub fn private_struct_attrs(options: &Options, errors: &Errors) -> proc_macro2::TokenStream {
+    let schema = schema::private_struct_attrs(options, errors);
+    let copy = copy::struct_attrs(options);
+
+    quote! {
+        #schema
+        #copy
+    }
+}
+
+/// Additional attributes for a `PhantomData` field of the public variant, which already has `field_attrs`
+pub fn phantom_field_attrs(
+    options: &Options,
+    struct_attrs: &Attrs,
+    field_attrs: &[syn::Attribute],
+) -> Vec<syn::Attribute> {
+    let mut attrs = schema::phantom_field_attrs(options);
+    attrs.extend(openapi::phantom_field_attrs(options));
+    attrs.extend(graphql::phantom_field_attrs(options));
+    attrs.extend(serde_skip_attr(options, struct_attrs, field_attrs));
+    attrs
+}
+
+/// `#[serde(skip)]` for the placeholders of private fields, so the JSON of the public variant
+/// matches the schemas and responses generated for it
+///
+/// The derives of `schemars` and `utoipa` accept `serde` attributes themselves,
+/// `axum` relies on the user deriving serde's traits for the public variant.
+fn serde_skip_attr(
+    options: &Options,
+    struct_attrs: &Attrs,
+    field_attrs: &[syn::Attribute],
+) -> Option<syn::Attribute> {
+    let serde_in_scope = (options.json_schema.is_some() && cfg!(feature = "schemars"))
+        || (options.openapi.is_some() && cfg!(feature = "utoipa"))
+        || (options.axum.is_some() && cfg!(feature = "axum") && derives_serde(struct_attrs));
+
+    (serde_in_scope && !skips_serde(field_attrs)).then(|| parse_quote!(#[serde(skip)]))
+}
+
+/// The path of the re-export of `dependency` as a string, for derives which take their crate path as a string
+fn reexport_path(krate: &syn::Path, dependency: &str) -> String {
+    format!("{}::__private::{dependency}", pretty_tokens(krate))
+}
+
+impl Errors {
+    /// Report an error at `span` if the cargo `feature` needed for the argument `arg` is not `enabled`
+    fn require_feature(&self, span: Span, arg: &str, feature: &str, enabled: bool) -> bool {
+        if !enabled {
+            self.spanned(
+                span,
+                format!("`{arg}` requires the `{feature}` feature of `sanitizeable`"),
+            );
+        }
+        enabled
+    }
+}
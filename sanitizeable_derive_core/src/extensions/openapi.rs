@@ -0,0 +1,31 @@
+use crate::datatypes::{Errors, Options};
+use quote::quote;
+use syn::parse_quote;
+
+/// Derive `utoipa::ToSchema` for the public variant only, so private fields never show up in API docs
+///
+/// The code generated by `utoipa` refers to `utoipa::...`, so it has to be a direct dependency.
+pub fn public_struct_attrs(options: &Options, errors: &Errors) -> proc_macro2::TokenStream {
+    let Some(span) = options.openapi else {
+        return proc_macro2::TokenStream::new();
+    };
+
+    if !errors.require_feature(span, "openapi", "utoipa", cfg!(feature = "utoipa")) {
+        return proc_macro2::TokenStream::new();
+    }
+
+    let krate = &options.crate_path;
+
+    quote! {
+        #[derive(#krate::__private::utoipa::ToSchema)]
+    }
+}
+
+/// The placeholders for private fields are not part of the schema
+pub fn phantom_field_attrs(options: &Options) -> Vec<syn::Attribute> {
+    if options.openapi.is_some() && cfg!(feature = "utoipa") {
+        vec![parse_quote!(#[schema(ignore)])]
+    } else {
+        Vec::new()
+    }
+}
@@ -0,0 +1,47 @@
+use super::reexport_path;
+use crate::datatypes::{Errors, Options};
+use proc_macro2::Span;
+use quote::quote;
+use syn::parse_quote;
+
+/// Derive `schemars::JsonSchema` through the re-export in `sanitizeable`
+fn derive_json_schema(
+    krate: &syn::Path,
+    errors: &Errors,
+    span: Span,
+    arg: &str,
+) -> proc_macro2::TokenStream {
+    if !errors.require_feature(span, arg, "schemars", cfg!(feature = "schemars")) {
+        return proc_macro2::TokenStream::new();
+    }
+
+    let schemars_path = reexport_path(krate, "schemars");
+
+    quote! {
+        #[derive(#krate::__private::schemars::JsonSchema)]
+        #[schemars(crate = #schemars_path)]
+    }
+}
+
+pub fn public_struct_attrs(options: &Options, errors: &Errors) -> proc_macro2::TokenStream {
+    match options.json_schema {
+        Some(span) => derive_json_schema(&options.crate_path, errors, span, "json_schema"),
+        None => proc_macro2::TokenStream::new(),
+    }
+}
+
+pub fn private_struct_attrs(options: &Options, errors: &Errors) -> proc_macro2::TokenStream {
+    match options.private_json_schema {
+        Some(span) => derive_json_schema(&options.crate_path, errors, span, "private_json_schema"),
+        None => proc_macro2::TokenStream::new(),
+    }
+}
+
+/// The placeholders for private fields are not part of the public schema
+pub fn phantom_field_attrs(options: &Options) -> Vec<syn::Attribute> {
+    if options.json_schema.is_some() && cfg!(feature = "schemars") {
+        vec![parse_quote!(#[schemars(skip)])]
+    } else {
+        Vec::new()
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{
+    datatypes::{Attrs, Errors, Fields, Names},
+    states::QuoteFields,
+    util::{build_phantom_fields, members, phantom_members},
+};
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, Index, ItemStruct, Member};
+
+/// Quote the `#sealed_name` struct as well as the `seal` and `unseal` methods
+///
+/// The sealed struct contains the public fields in the clear, followed by the same
+/// `PhantomData` fields as the public variant and a single `ciphertext` field
+/// which holds all private fields encrypted as one blob.
+/// The public fields are authenticated as associated data, so a ciphertext only unseals
+/// together with the public fields it was sealed with.
+pub fn quote_seal(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input:
+            ItemStruct {
+                vis,
+                generics,
+                fields: input_fields,
+                ..
+            },
+        names:
+            Names {
+                private_name,
+                container_name,
+                sealed_name,
+                ..
+            },
+        options,
+        errors,
+        split_fields:
+            Fields {
+                private_fields,
+                public_fields,
+                phantom_fields,
+                ..
+            },
+        ..
+    } = state;
+    let krate = &options.crate_path;
+
+    let Some(span) = options.seal else {
+        return proc_macro2::TokenStream::new();
+    };
+
+    if !errors.require_feature(span, "seal", "encryption", cfg!(feature = "encryption"))
+        || matches!(input_fields, syn::Fields::Unit)
+        || !check_ciphertext_name(public_fields, errors)
+    {
+        return proc_macro2::TokenStream::new();
+    }
+    let named = matches!(input_fields, syn::Fields::Named(_));
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let turbofish = ty_generics.as_turbofish();
+
+    let private_only_fields = &private_fields[public_fields.len()..];
+
+    let public_members = members(public_fields);
+    let public_types = public_fields
+        .iter()
+        .map(|field| &field.ty)
+        .collect::<Vec<_>>();
+    let private_members = members(private_fields)[public_fields.len()..].to_vec();
+    let private_types = private_only_fields
+        .iter()
+        .map(|field| &field.ty)
+        .collect::<Vec<_>>();
+    let tuple_indices = (0..private_members.len()).map(Index::from);
+
+    let phantom_members = phantom_members(phantom_fields, public_fields.len());
+    let ciphertext = ciphertext_member(named, public_fields.len() + phantom_fields.len());
+    let sealed_struct = quote_sealed_struct(state, &ciphertext);
+    let seal_associated_data = quote_associated_data(state, &quote!(private));
+    let unseal_associated_data = quote_associated_data(state, &quote!(self));
+
+    quote! {
+        #sealed_struct
+
+        impl #impl_generics #container_name #ty_generics #where_clause {
+            /// Encrypt all private fields into a single blob using `cipher`,
+            /// while keeping copies of the public fields in the clear
+            #vis fn seal(
+                &self,
+                cipher: &impl #krate::encryption::Cipher,
+            ) -> ::core::result::Result<#sealed_name #ty_generics, #krate::encryption::SealError>
+            where
+                #(#public_types: ::core::clone::Clone + #krate::__private::serde::Serialize,)*
+                #(#private_types: #krate::__private::serde::Serialize,)*
+            {
+                let private = #krate::Sanitizeable::private(self);
+
+                let plaintext = #krate::__private::bincode::serialize(
+                    &( #(&private.#private_members,)* )
+                )?;
+                let ciphertext = cipher.encrypt(&plaintext, &#seal_associated_data)?;
+
+                ::core::result::Result::Ok(#sealed_name {
+                    #(#public_members: ::core::clone::Clone::clone(&private.#public_members),)*
+                    #(#phantom_members: ::core::marker::PhantomData,)*
+                    #ciphertext: ciphertext,
+                })
+            }
+        }
+
+        impl #impl_generics #sealed_name #ty_generics #where_clause {
+            /// Decrypt the private fields using `cipher` and reassemble the container
+            ///
+            /// This fails if the ciphertext or the public fields were tampered with,
+            /// or if it was sealed with a different key.
+            #vis fn unseal(
+                self,
+                cipher: &impl #krate::encryption::Cipher,
+            ) -> ::core::result::Result<#container_name #ty_generics, #krate::encryption::SealError>
+            where
+                #(#public_types: #krate::__private::serde::Serialize,)*
+                #(#private_types: #krate::__private::serde::de::DeserializeOwned,)*
+            {
+                let plaintext = cipher.decrypt(&self.#ciphertext, &#unseal_associated_data)?;
+                let private: ( #(#private_types,)* ) =
+                    #krate::__private::bincode::deserialize(&plaintext)?;
+
+                ::core::result::Result::Ok(<#container_name #ty_generics as #krate::Sanitizeable>::from_private(
+                    #private_name #turbofish {
+                        #(#public_members: self.#public_members,)*
+                        #(#private_members: private.#tuple_indices,)*
+                    }
+                ))
+            }
+        }
+    }
+}
+
+/// Quote the associated data of the ciphertext, which authenticates the name of the struct
+/// and the public fields of `source`
+fn quote_associated_data(
+    state: &QuoteFields,
+    source: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        names: Names { private_name, .. },
+        options,
+        split_fields: Fields { public_fields, .. },
+        ..
+    } = state;
+    let krate = &options.crate_path;
+    let public_members = members(public_fields);
+
+    quote! {
+        #krate::__private::bincode::serialize(
+            &( ::core::stringify!(#private_name), ( #(&#source.#public_members,)* ) )
+        )?
+    }
+}
+
+/// Report an error if a public field would collide with the `ciphertext` field of the sealed struct
+fn check_ciphertext_name(public_fields: &[syn::Field], errors: &Errors) -> bool {
+    let Some(ident) = public_fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .find(|ident| ident.unraw() == "ciphertext")
+    else {
+        return true;
+    };
+
+    errors.spanned(
+        ident.span(),
+        "`seal` adds a `ciphertext` field to the sealed struct, so a public field can not have that name",
+    );
+    false
+}
+
+fn ciphertext_member(named: bool, index: usize) -> Member {
+    if named {
+        Member::Named(format_ident!("ciphertext"))
+    } else {
+        Member::Unnamed(Index::from(index))
+    }
+}
+
+fn quote_sealed_struct(state: &QuoteFields, ciphertext: &Member) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input: ItemStruct { vis, generics, .. },
+        names: Names { sealed_name, .. },
+        struct_attrs:
+            Attrs {
+                sealed_attrs,
+                normal_attrs,
+                ..
+            },
+        split_fields:
+            Fields {
+                phantom_fields,
+                sealed_fields,
+                ..
+            },
+        options,
+        ..
+    } = state;
+
+    let phantom = build_phantom_fields(phantom_fields.clone(), options.phantom.as_ref());
+
+    let fields = match ciphertext {
+        Member::Named(ciphertext) => quote! {
+            {
+                #(#sealed_fields,)*
+                #phantom
+                /// All private fields, serialized and encrypted as one authenticated blob
+                #vis #ciphertext: ::std::vec::Vec<::core::primitive::u8>,
+            }
+        },
+        Member::Unnamed(_) => quote! {
+            (
+                #(#sealed_fields,)*
+                #phantom
+                #vis ::std::vec::Vec<::core::primitive::u8>,
+            );
+        },
+    };
+
+    quote! {
+        #(#sealed_attrs)*
+        #(#normal_attrs)*
+        #vis struct #sealed_name #generics #fields
+    }
+}
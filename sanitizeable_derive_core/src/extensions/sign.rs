@@ -0,0 +1,119 @@
+use crate::{
+    datatypes::{Fields, Names},
+    states::QuoteFields,
+    util::{extend_where_clause, members},
+};
+use quote::quote;
+use syn::{
+    parse_quote,
+    visit_mut::{self, VisitMut},
+    ItemStruct, PathSegment,
+};
+
+/// Collections whose iteration order, and therefore their bincode encoding, differs between equal values
+const UNORDERED_TYPES: &[&str] = &["HashMap", "HashSet"];
+
+/// Finds the first of the `UNORDERED_TYPES` mentioned in a type
+#[derive(Default)]
+struct FindUnordered(Option<PathSegment>);
+
+impl VisitMut for FindUnordered {
+    fn visit_path_segment_mut(&mut self, segment: &mut PathSegment) {
+        if self.0.is_none() && UNORDERED_TYPES.contains(&segment.ident.to_string().as_str()) {
+            self.0 = Some(segment.clone());
+        }
+        visit_mut::visit_path_segment_mut(self, segment);
+    }
+}
+
+/// Quote the `CanonicalEncoding` implementation of the public variant and the `sign_public` method
+///
+/// The encoding only covers the public fields (prefixed by the name of the public variant),
+/// so signatures stay valid no matter what happens to the private fields.
+/// It is bincode, which is only canonical if equal values serialize equally, so public fields
+/// mentioning `HashMap` or `HashSet` are rejected. Unordered collections hidden behind aliases or
+/// other types are not detected and can make signatures fail to verify after a round trip.
+pub fn quote_sign(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input: ItemStruct { vis, generics, .. },
+        names: Names {
+            public_name,
+            container_name,
+            ..
+        },
+        options,
+        errors,
+        split_fields: Fields { public_fields, .. },
+        ..
+    } = state;
+    let krate = &options.crate_path;
+
+    let Some(span) = options.sign else {
+        return proc_macro2::TokenStream::new();
+    };
+
+    if !errors.require_feature(span, "sign", "signing", cfg!(feature = "signing")) {
+        return proc_macro2::TokenStream::new();
+    }
+
+    for field in public_fields {
+        let mut find = FindUnordered::default();
+        find.visit_type_mut(&mut field.ty.clone());
+        if let Some(segment) = find.0 {
+            errors.push(syn::Error::new_spanned(
+                &segment.ident,
+                format!(
+                    "`{}` has no canonical encoding because its iteration order is random, so it can not be signed; \
+                     consider using `BTreeMap` or `BTreeSet`, or marking the field #[private]",
+                    segment.ident,
+                ),
+            ));
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let public_members = members(public_fields);
+    let encoding_where_clause = extend_where_clause(
+        generics,
+        public_fields.iter().map(|field| -> syn::WherePredicate {
+            let ty = &field.ty;
+            parse_quote!(#ty: #krate::__private::serde::Serialize)
+        }),
+    );
+
+    quote! {
+        impl #impl_generics #krate::signing::CanonicalEncoding for #public_name #ty_generics
+            #encoding_where_clause
+        {
+            fn canonical_encoding(
+                &self,
+            ) -> ::core::result::Result<::std::vec::Vec<::core::primitive::u8>, #krate::signing::SignatureError> {
+                ::core::result::Result::Ok(#krate::__private::bincode::serialize(
+                    &( ::core::stringify!(#public_name), #(&self.#public_members,)* )
+                )?)
+            }
+        }
+
+        impl #impl_generics #container_name #ty_generics #where_clause {
+            /// Sign a copy of the public variant with `key`
+            ///
+            /// Use `Signed::verify` to check that the public fields have not been tampered with.
+            #vis fn sign_public(
+                &self,
+                key: &#krate::signing::SigningKey,
+            ) -> ::core::result::Result<
+                #krate::signing::Signed<#public_name #ty_generics>,
+                #krate::signing::SignatureError,
+            >
+            where
+                #public_name #ty_generics: ::core::clone::Clone + #krate::signing::CanonicalEncoding,
+            {
+                #krate::signing::Signed::sign(
+                    ::core::clone::Clone::clone(#krate::Sanitizeable::public(self)),
+                    key,
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    datatypes::{Fields, Names},
+    states::QuoteFields,
+    util::{extend_generics, extend_where_clause, phantom_members},
+};
+use quote::quote;
+use syn::{parse_quote, Field, Generics, ItemStruct};
+
+/// Quote a `sqlx::FromRow` implementation for `ty` which reads `fields` from `columns`
+fn quote_from_row(
+    krate: &syn::Path,
+    generics: &Generics,
+    ty: &proc_macro2::TokenStream,
+    fields: &[Field],
+    columns: &[String],
+    construct: impl FnOnce(proc_macro2::TokenStream) -> proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let row_generics = extend_generics(
+        generics,
+        [
+            parse_quote!('__r),
+            parse_quote!(__R: #krate::__private::sqlx::Row),
+        ],
+    );
+    let (impl_generics, _, _) = row_generics.split_for_impl();
+
+    let mut predicates: Vec<syn::WherePredicate> =
+        vec![parse_quote!(&'__r ::core::primitive::str: #krate::__private::sqlx::ColumnIndex<__R>)];
+    predicates.extend(fields.iter().map(|field| -> syn::WherePredicate {
+        let ty = &field.ty;
+        parse_quote! {
+            #ty: #krate::__private::sqlx::Decode<'__r, <__R as #krate::__private::sqlx::Row>::Database>
+                + #krate::__private::sqlx::Type<<__R as #krate::__private::sqlx::Row>::Database>
+        }
+    }));
+    let where_clause = extend_where_clause(generics, predicates);
+
+    let idents = fields.iter().map(|field| &field.ident);
+    let values = quote! {
+        #(#idents: #krate::__private::sqlx::Row::try_get(row, #columns)?,)*
+    };
+    let value = construct(values);
+
+    quote! {
+        impl #impl_generics #krate::__private::sqlx::FromRow<'__r, __R> for #ty #where_clause {
+            fn from_row(row: &'__r __R) -> ::core::result::Result<Self, #krate::__private::sqlx::Error> {
+                ::core::result::Result::Ok(#value)
+            }
+        }
+    }
+}
+
+/// Quote `sqlx::FromRow` for the container and the public variant, as well as the column lists of both variants
+pub fn quote_sqlx(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input: ItemStruct { vis, generics, .. },
+        names:
+            Names {
+                public_name,
+                private_name,
+                container_name,
+                ..
+            },
+        options,
+        errors,
+        split_fields:
+            Fields {
+                private_fields,
+                public_fields,
+                phantom_fields,
+                columns,
+                ..
+            },
+        ..
+    } = state;
+    let krate = &options.crate_path;
+
+    let Some(span) = options.sqlx else {
+        return proc_macro2::TokenStream::new();
+    };
+
+    if !errors.require_feature(span, "sqlx", "sqlx", cfg!(feature = "sqlx")) {
+        return proc_macro2::TokenStream::new();
+    }
+
+    if private_fields.iter().any(|field| field.ident.is_none()) {
+        state
+            .errors
+            .spanned(span, "`sqlx` can only be used on structs with named fields");
+        return proc_macro2::TokenStream::new();
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let container = quote_from_row(
+        krate,
+        generics,
+        &quote!(#container_name #ty_generics),
+        private_fields,
+        columns,
+        |values| quote!(#krate::Sanitizeable::from_private(#private_name { #values })),
+    );
+
+    let phantom_members = phantom_members(phantom_fields, public_fields.len());
+    let public = quote_from_row(
+        krate,
+        generics,
+        &quote!(#public_name #ty_generics),
+        public_fields,
+        &columns[..public_fields.len()],
+        |values| {
+            quote! {
+                Self {
+                    #values
+                    #(#phantom_members: ::core::marker::PhantomData,)*
+                }
+            }
+        },
+    );
+
+    let public_columns = &columns[..public_fields.len()];
+
+    quote! {
+        #container
+        #public
+
+        impl #impl_generics #container_name #ty_generics #where_clause {
+            /// The columns needed to load the public variant
+            #vis const PUBLIC_COLUMNS: &'static [&'static ::core::primitive::str] = &[#(#public_columns),*];
+            /// The columns needed to load the container or the private variant
+            #vis const PRIVATE_COLUMNS: &'static [&'static ::core::primitive::str] = &[#(#columns),*];
+        }
+    }
+}
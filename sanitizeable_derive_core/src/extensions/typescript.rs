@@ -0,0 +1,446 @@
+use crate::{
+    datatypes::{Fields, Names},
+    states::QuoteFields,
+};
+use quote::{format_ident, quote};
+use std::fmt::Write;
+use syn::{
+    ext::IdentExt, Attribute, Field, GenericArgument, GenericParam, ItemStruct, Lit, Meta,
+    NestedMeta, PathArguments, Type,
+};
+
+/// Quote the `typescript_definition` function of the public variant
+///
+/// The definition is built while expanding the macro, so types are only known by name:
+/// Rust primitives and common containers are mapped to their TypeScript equivalents
+/// and all other types (e.g. a nested `AddressPublic`) are referenced by their name.
+/// Like `serde_json`, the placeholders for private fields are included as `null` unless serde skips them,
+/// and `#[serde(rename = "...")]`, `#[serde(rename_all = "...")]` and `#[serde(skip)]` are respected.
+/// Whether an unknown type is a container is only known to the compiler, so the name of the public interface
+/// of a nested container (e.g. `AddressPublic` for `Address`) is looked up while building the definition.
+pub fn quote_typescript(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input:
+            ItemStruct {
+                vis,
+                generics,
+                fields: input_fields,
+                ..
+            },
+        names: Names { public_name, .. },
+        options,
+        struct_attrs,
+        split_fields:
+            Fields {
+                public_fields,
+                phantom_fields,
+                ..
+            },
+        ..
+    } = state;
+    let krate = &options.crate_path;
+
+    if options.typescript.is_none() {
+        return proc_macro2::TokenStream::new();
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let type_params = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(param) => Some(param.ident.to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let type_params = if type_params.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", type_params.join(", "))
+    };
+
+    let struct_attrs_of_public = [
+        struct_attrs.public_attrs.as_slice(),
+        &struct_attrs.normal_attrs,
+    ]
+    .concat();
+    let rename_all = serde_string(&serde_args(&struct_attrs_of_public), "rename_all");
+
+    let properties = serialized_fields(state);
+
+    let mut definition = TsType::from(doc_comment(&struct_attrs_of_public, ""));
+    if let syn::Fields::Named(_) = input_fields {
+        definition.push(format!("export interface {public_name}{type_params} {{\n"));
+        for (field, ty) in properties {
+            let args = serde_args(&field.attrs);
+            let name = field.ident.as_ref().expect("named field without a name");
+            let name = serde_string(&args, "rename")
+                .unwrap_or_else(|| rename_field(&name.unraw().to_string(), rename_all.as_deref()));
+            let optional = if serde_string(&args, "skip_serializing_if").is_some() {
+                "?"
+            } else {
+                ""
+            };
+            definition.push(doc_comment(&field.attrs, "  "));
+            definition.push(format!("  {}{optional}: ", property_name(&name)));
+            definition.extend(ty);
+            definition.push(";\n");
+        }
+        definition.push("}\n");
+    } else if properties.len() == 1 && public_fields.len() + phantom_fields.len() == 1 {
+        // serde serializes newtype structs as their only field
+        let (_, ty) = properties.into_iter().next().expect("one property");
+        definition.push(format!("export type {public_name}{type_params} = "));
+        definition.extend(ty);
+        definition.push(";\n");
+    } else {
+        definition.push(format!("export type {public_name}{type_params} = ["));
+        definition.extend(TsType::join(properties.into_iter().map(|(_, ty)| ty), ", "));
+        definition.push("];\n");
+    }
+
+    let definition = definition.quote(krate);
+    let interface_name = public_name.to_string();
+
+    quote! {
+        impl #impl_generics #public_name #ty_generics #where_clause {
+            /// The TypeScript definition of this struct's JSON representation
+            #vis fn typescript_definition() -> &'static ::core::primitive::str {
+                #definition
+            }
+        }
+
+        impl #impl_generics #krate::typescript::TypeScriptInterface for #public_name #ty_generics #where_clause {
+            const NAME: &'static ::core::primitive::str = #interface_name;
+        }
+    }
+}
+
+/// A TypeScript type, or any other part of a definition, which may contain the names of nested interfaces
+#[derive(Default)]
+struct TsType(Vec<Piece>);
+
+enum Piece {
+    Text(String),
+    /// The name of the interface of `Type`'s public variant if it is a container, and the given name otherwise
+    InterfaceName(Box<Type>, String),
+}
+
+impl TsType {
+    fn push(&mut self, text: impl Into<String>) {
+        let text = text.into();
+        match self.0.last_mut() {
+            Some(Piece::Text(last)) => last.push_str(&text),
+            _ => self.0.push(Piece::Text(text)),
+        }
+    }
+
+    fn extend(&mut self, other: TsType) {
+        for piece in other.0 {
+            match piece {
+                Piece::Text(text) => self.push(text),
+                piece @ Piece::InterfaceName(..) => self.0.push(piece),
+            }
+        }
+    }
+
+    fn join(types: impl IntoIterator<Item = TsType>, separator: &str) -> TsType {
+        let mut joined = TsType::default();
+        for (i, ty) in types.into_iter().enumerate() {
+            if i > 0 {
+                joined.push(separator);
+            }
+            joined.extend(ty);
+        }
+        joined
+    }
+
+    /// Whether the type has to be put in parentheses to be used as the element of an array
+    fn is_compound(&self) -> bool {
+        self.0
+            .iter()
+            .any(|piece| matches!(piece, Piece::Text(text) if text.contains(' ')))
+    }
+
+    /// Quote an expression for the `&'static str` of the definition,
+    /// which is a literal unless an interface name has to be looked up
+    fn quote(self, krate: &syn::Path) -> proc_macro2::TokenStream {
+        if let [Piece::Text(text)] = self.0.as_slice() {
+            return quote!(#text);
+        }
+
+        let pieces = self.0.into_iter().map(|piece| match piece {
+            Piece::Text(text) => quote!(#text),
+            Piece::InterfaceName(ty, fallback) => quote! {
+                (&#krate::typescript::Probe::<#ty>::new()).interface_name(#fallback)
+            },
+        });
+
+        quote! {{
+            #[allow(unused_imports)]
+            use #krate::typescript::{ContainerName as _, OtherName as _};
+
+            static DEFINITION: ::std::sync::OnceLock<::std::string::String> = ::std::sync::OnceLock::new();
+            DEFINITION.get_or_init(|| [#(#pieces),*].concat())
+        }}
+    }
+}
+
+impl From<String> for TsType {
+    fn from(text: String) -> Self {
+        TsType(vec![Piece::Text(text)])
+    }
+}
+
+impl From<&str> for TsType {
+    fn from(text: &str) -> Self {
+        TsType::from(text.to_string())
+    }
+}
+
+/// The fields of the public variant which serde serializes, with their TypeScript types
+///
+/// The placeholders get the same attributes as in the public variant, including a generated `#[serde(skip)]`.
+fn serialized_fields(state: &QuoteFields) -> Vec<(Field, TsType)> {
+    let QuoteFields {
+        options,
+        struct_attrs,
+        split_fields:
+            Fields {
+                public_fields,
+                phantom_fields,
+                ..
+            },
+        ..
+    } = state;
+
+    let phantom_fields = phantom_fields.iter().map(|field| {
+        let mut field = field.clone();
+        let attrs = super::phantom_field_attrs(options, struct_attrs, &field.attrs);
+        field.attrs.extend(attrs);
+        field.ident = field
+            .ident
+            .map(|ident| format_ident!("_{}", ident.unraw(), span = ident.span()));
+        (field, TsType::from("null"))
+    });
+
+    public_fields
+        .iter()
+        .map(|field| (field.clone(), typescript_type(&field.ty)))
+        .chain(phantom_fields)
+        .filter(|(field, _)| {
+            let args = serde_args(&field.attrs);
+            !(serde_flag(&args, "skip") || serde_flag(&args, "skip_serializing"))
+        })
+        .collect()
+}
+
+/// The arguments of all `#[serde(...)]` attributes in `attrs`
+fn serde_args(attrs: &[Attribute]) -> Vec<NestedMeta> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("serde"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+fn serde_flag(args: &[NestedMeta], key: &str) -> bool {
+    args.iter()
+        .any(|arg| matches!(arg, NestedMeta::Meta(Meta::Path(path)) if path.is_ident(key)))
+}
+
+/// The value of `key = "..."` or of `key(serialize = "...")`, which is what serde uses when serializing
+fn serde_string(args: &[NestedMeta], key: &str) -> Option<String> {
+    let string = |lit: &Lit| match lit {
+        Lit::Str(string) => Some(string.value()),
+        _ => None,
+    };
+
+    args.iter().find_map(|arg| match arg {
+        NestedMeta::Meta(Meta::NameValue(meta)) if meta.path.is_ident(key) => string(&meta.lit),
+        NestedMeta::Meta(Meta::List(list)) if list.path.is_ident(key) => {
+            list.nested.iter().find_map(|nested| match nested {
+                NestedMeta::Meta(Meta::NameValue(meta)) if meta.path.is_ident("serialize") => {
+                    string(&meta.lit)
+                }
+                _ => None,
+            })
+        }
+        _ => None,
+    })
+}
+
+/// Apply a `#[serde(rename_all = "...")]` rule to the name of a field, the same way serde does
+fn rename_field(field: &str, rule: Option<&str>) -> String {
+    let pascal_case = || {
+        let mut pascal = String::new();
+        let mut capitalize = true;
+        for ch in field.chars() {
+            if ch == '_' {
+                capitalize = true;
+            } else if capitalize {
+                pascal.push(ch.to_ascii_uppercase());
+                capitalize = false;
+            } else {
+                pascal.push(ch);
+            }
+        }
+        pascal
+    };
+
+    match rule {
+        Some("UPPERCASE" | "SCREAMING_SNAKE_CASE") => field.to_ascii_uppercase(),
+        Some("PascalCase") => pascal_case(),
+        Some("camelCase") => {
+            let pascal = pascal_case();
+            let mut chars = pascal.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_ascii_lowercase().to_string() + chars.as_str()
+            })
+        }
+        Some("kebab-case") => field.replace('_', "-"),
+        Some("SCREAMING-KEBAB-CASE") => field.to_ascii_uppercase().replace('_', "-"),
+        _ => field.to_string(),
+    }
+}
+
+/// Quote a property name if it is not a valid identifier, e.g. after renaming it to kebab-case
+fn property_name(name: &str) -> String {
+    let is_identifier = name
+        .chars()
+        .next()
+        .is_some_and(|first| !first.is_ascii_digit())
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '$');
+
+    if is_identifier {
+        name.to_string()
+    } else {
+        format!("{name:?}")
+    }
+}
+
+/// Turn `#[doc = "..."]` attributes into a TypeScript doc comment
+fn doc_comment(attrs: &[syn::Attribute], indent: &str) -> String {
+    let lines = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(meta)) => match meta.lit {
+                Lit::Str(string) => Some(string.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    match lines.as_slice() {
+        [] => String::new(),
+        [line] => format!("{indent}/** {line} */\n"),
+        lines => {
+            let mut out = format!("{indent}/**\n");
+            for line in lines {
+                let _ = writeln!(out, "{indent} * {line}");
+            }
+            let _ = writeln!(out, "{indent} */");
+            out
+        }
+    }
+}
+
+/// Map a Rust type to the TypeScript type of its `serde_json` representation
+fn typescript_type(ty: &Type) -> TsType {
+    match ty {
+        Type::Reference(reference) => typescript_type(&reference.elem),
+        Type::Paren(paren) => typescript_type(&paren.elem),
+        Type::Group(group) => typescript_type(&group.elem),
+        Type::Array(array) => array_of(&array.elem),
+        Type::Slice(slice) => array_of(&slice.elem),
+        Type::Tuple(tuple) if tuple.elems.is_empty() => TsType::from("null"),
+        Type::Tuple(tuple) => {
+            let mut ts = TsType::from("[");
+            ts.extend(TsType::join(tuple.elems.iter().map(typescript_type), ", "));
+            ts.push("]");
+            ts
+        }
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last().expect("empty type path");
+            let args = match &segment.arguments {
+                PathArguments::AngleBracketed(args) => args
+                    .args
+                    .iter()
+                    .filter_map(|arg| match arg {
+                        GenericArgument::Type(ty) => Some(ty),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+            path_type(ty, &segment.ident.to_string(), &args)
+        }
+        _ => TsType::from("unknown"),
+    }
+}
+
+fn path_type(ty: &Type, name: &str, args: &[&Type]) -> TsType {
+    match (name, args) {
+        ("String" | "str" | "char", _) => TsType::from("string"),
+        (
+            "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128"
+            | "isize" | "f32" | "f64",
+            _,
+        ) => TsType::from("number"),
+        ("bool", _) => TsType::from("boolean"),
+        ("Option", [inner]) => {
+            let mut ts = typescript_type(inner);
+            ts.push(" | null");
+            ts
+        }
+        ("Vec" | "VecDeque" | "HashSet" | "BTreeSet", [inner]) => array_of(inner),
+        ("HashMap" | "BTreeMap", [key, value]) => {
+            let mut ts = TsType::from("Record<");
+            ts.extend(typescript_type(key));
+            ts.push(", ");
+            ts.extend(typescript_type(value));
+            ts.push(">");
+            ts
+        }
+        ("Box" | "Rc" | "Arc" | "Cow", [inner]) => typescript_type(inner),
+        // Any other type may be a container, which is described by the interface of its public variant
+        (name, args) => {
+            let mut ts = TsType(vec![Piece::InterfaceName(
+                Box::new(ty.clone()),
+                name.to_string(),
+            )]);
+            if !args.is_empty() {
+                ts.push("<");
+                ts.extend(TsType::join(
+                    args.iter().map(|ty| typescript_type(ty)),
+                    ", ",
+                ));
+                ts.push(">");
+            }
+            ts
+        }
+    }
+}
+
+fn array_of(elem: &Type) -> TsType {
+    let elem = typescript_type(elem);
+    let mut ts = TsType::default();
+    if elem.is_compound() {
+        ts.push("(");
+        ts.extend(elem);
+        ts.push(")[]");
+    } else {
+        ts.extend(elem);
+        ts.push("[]");
+    }
+    ts
+}
@@ -0,0 +1,122 @@
+use crate::{
+    datatypes::{Fields, Names},
+    states::QuoteFields,
+    util::{extend_where_clause, members},
+};
+use quote::quote;
+use syn::{ext::IdentExt, parse_quote, ItemStruct};
+
+/// Quote `valuable::Valuable` and `valuable::Structable` for the public variant,
+/// and a `Valuable` implementation for the container which only shows the public variant
+pub fn quote_valuable(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input: ItemStruct { generics, .. },
+        names: Names {
+            public_name,
+            container_name,
+            ..
+        },
+        options,
+        errors,
+        split_fields: Fields { public_fields, .. },
+        ..
+    } = state;
+    let krate = &options.crate_path;
+
+    let Some(span) = options.valuable else {
+        return proc_macro2::TokenStream::new();
+    };
+
+    if !errors.require_feature(span, "valuable", "valuable", cfg!(feature = "valuable")) {
+        return proc_macro2::TokenStream::new();
+    }
+
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+
+    let public_members = members(public_fields);
+    let public_where_clause = extend_where_clause(
+        generics,
+        public_fields.iter().map(|field| -> syn::WherePredicate {
+            let ty = &field.ty;
+            parse_quote!(#ty: #krate::__private::valuable::Valuable)
+        }),
+    );
+    let container_where_clause = extend_where_clause(
+        generics,
+        [parse_quote!(#public_name #ty_generics: #krate::__private::valuable::Valuable)],
+    );
+
+    let (fields_definition, visit) = if public_fields.iter().all(|field| field.ident.is_some()) {
+        let names = public_fields
+            .iter()
+            .map(|field| field.ident.as_ref().unwrap().unraw().to_string());
+        let named_fields = quote! {
+            {
+                const FIELDS: &[#krate::__private::valuable::NamedField<'static>] =
+                    &[#(#krate::__private::valuable::NamedField::new(#names)),*];
+                FIELDS
+            }
+        };
+        (
+            quote! { #krate::__private::valuable::Fields::Named(#named_fields) },
+            quote! {
+                visit.visit_named_fields(&#krate::__private::valuable::NamedValues::new(
+                    #named_fields,
+                    &[#(#krate::__private::valuable::Valuable::as_value(&self.#public_members)),*],
+                ));
+            },
+        )
+    } else {
+        let len = public_fields.len();
+        (
+            quote! { #krate::__private::valuable::Fields::Unnamed(#len) },
+            quote! {
+                visit.visit_unnamed_fields(
+                    &[#(#krate::__private::valuable::Valuable::as_value(&self.#public_members)),*],
+                );
+            },
+        )
+    };
+
+    quote! {
+        impl #impl_generics #krate::__private::valuable::Structable for #public_name #ty_generics
+            #public_where_clause
+        {
+            fn definition(&self) -> #krate::__private::valuable::StructDef<'_> {
+                #krate::__private::valuable::StructDef::new_static(
+                    ::core::stringify!(#public_name),
+                    #fields_definition,
+                )
+            }
+        }
+
+        impl #impl_generics #krate::__private::valuable::Valuable for #public_name #ty_generics
+            #public_where_clause
+        {
+            fn as_value(&self) -> #krate::__private::valuable::Value<'_> {
+                #krate::__private::valuable::Value::Structable(self)
+            }
+
+            fn visit(&self, visit: &mut dyn #krate::__private::valuable::Visit) {
+                #visit
+            }
+        }
+
+        impl #impl_generics #krate::__private::valuable::Valuable for #container_name #ty_generics
+            #container_where_clause
+        {
+            fn as_value(&self) -> #krate::__private::valuable::Value<'_> {
+                #krate::__private::valuable::Valuable::as_value(
+                    #krate::Sanitizeable::public(self),
+                )
+            }
+
+            fn visit(&self, visit: &mut dyn #krate::__private::valuable::Visit) {
+                #krate::__private::valuable::Valuable::visit(
+                    #krate::Sanitizeable::public(self),
+                    visit,
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{
+    datatypes::{Fields, Names},
+    states::QuoteFields,
+};
+use quote::quote;
+use syn::ItemStruct;
+
+/// Quote the `create_public_view_sql` function of the container, which creates a SQL view of only the public columns
+pub fn quote_view_sql(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input: ItemStruct { vis, generics, .. },
+        names: Names { container_name, .. },
+        split_fields:
+            Fields {
+                public_fields,
+                columns,
+                ..
+            },
+        options,
+        errors,
+        ..
+    } = state;
+    let krate = &options.crate_path;
+
+    let Some(span) = options.view_sql else {
+        return proc_macro2::TokenStream::new();
+    };
+
+    // Tuple structs do not have column names
+    if public_fields.iter().any(|field| field.ident.is_none()) {
+        errors.spanned(span, "`view_sql` requires a struct with named fields");
+        return proc_macro2::TokenStream::new();
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let public_columns = &columns[..public_fields.len()];
+
+    quote! {
+        impl #impl_generics #container_name #ty_generics #where_clause {
+            /// A SQL statement creating `view`, which only exposes the public columns of `table`
+            #vis fn create_public_view_sql(table: &::core::primitive::str, view: &::core::primitive::str) -> ::std::string::String {
+                #krate::sql::create_view(table, view, &[#(#public_columns),*])
+            }
+        }
+    }
+}
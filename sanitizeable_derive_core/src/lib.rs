@@ -0,0 +1,109 @@
+//! The expansion of `#[sanitizeable]`, which only depends on `proc_macro2`
+//!
+//! `sanitizeable_derive` is a thin wrapper around this crate.
+//! Using it directly allows testing expansions and running them outside of a proc macro, e.g. in build scripts.
+
+#![deny(clippy::pedantic)]
+#![allow(clippy::struct_field_names)]
+
+mod datatypes;
+mod extensions;
+mod state_machine;
+mod states;
+mod util;
+
+use proc_macro2::{Span, TokenStream};
+use syn::{parse::Parser, punctuated::Punctuated, AttributeArgs, ItemStruct, NestedMeta, Token};
+
+/// The items generated for a struct
+pub struct Expansion {
+    pub tokens: TokenStream,
+    /// Things which are suspicious but allowed, which a proc macro can emit as warnings
+    pub warnings: Vec<Warning>,
+}
+
+pub struct Warning {
+    pub span: Span,
+    pub message: String,
+}
+
+/// The manifest entry of a struct for `sanitizeable::audit`
+pub struct ManifestEntry {
+    /// The name of the container type
+    pub container_name: String,
+    pub json: String,
+}
+
+fn parse(
+    args: TokenStream,
+    item: TokenStream,
+) -> Result<(AttributeArgs, ItemStruct), Vec<syn::Error>> {
+    let args = Punctuated::<NestedMeta, Token![,]>::parse_terminated
+        .parse2(args)
+        .map_err(|error| vec![error])?;
+    let item = syn::parse2(item).map_err(|error| vec![error])?;
+
+    Ok((args.into_iter().collect(), item))
+}
+
+/// Expand `#[sanitizeable(args)] item`
+///
+/// # Errors
+///
+/// Returns every problem found in `args` and `item`
+pub fn expand(args: TokenStream, item: TokenStream) -> Result<TokenStream, Vec<syn::Error>> {
+    expand_with_warnings(args, item).map(|expansion| expansion.tokens)
+}
+
+/// Expand `#[sanitizeable(args)] item`, keeping the warnings about the input
+///
+/// # Errors
+///
+/// Returns every problem found in `args` and `item`
+pub fn expand_with_warnings(
+    args: TokenStream,
+    item: TokenStream,
+) -> Result<Expansion, Vec<syn::Error>> {
+    let (args, item) = parse(args, item)?;
+    state_machine::run(args, item)
+}
+
+/// Stand-ins for the types `#[sanitizeable(args)] item` would create, with `Sanitizeable` implemented for the container
+///
+/// Emitting them next to the errors of a failed expansion keeps code using the struct
+/// from reporting follow-on errors about missing types or methods.
+pub fn fallback(args: TokenStream, item: TokenStream) -> TokenStream {
+    let Ok(item) = syn::parse2(item) else {
+        return TokenStream::new();
+    };
+    let args = Punctuated::<NestedMeta, Token![,]>::parse_terminated
+        .parse2(args)
+        .map(|args| args.into_iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    util::fallback_items(&args, &item)
+}
+
+/// Describe `#[sanitizeable(args)] item` for the audit manifest
+///
+/// The entry contains the name of the crate and the source location of the struct,
+/// which only the compiler knows, so `location` has to return the file and line of a span.
+///
+/// # Errors
+///
+/// Returns an error if `args` or `item` can not be parsed
+pub fn audit_manifest_entry(
+    args: TokenStream,
+    item: TokenStream,
+    krate: &str,
+    location: impl FnOnce(Span) -> (String, usize),
+) -> Result<ManifestEntry, Vec<syn::Error>> {
+    let (args, item) = parse(args, item)?;
+    let state = state_machine::analyze(args, item);
+    let (file, line) = location(state.input.ident.span());
+
+    Ok(ManifestEntry {
+        container_name: state.names.container_name.to_string(),
+        json: extensions::audit::manifest_entry(&state, krate, &file, line),
+    })
+}
@@ -0,0 +1,383 @@
+use crate::Expansion;
+use crate::{
+    datatypes::{Attrs, Errors, FieldTokenStreams, Fields, Names},
+    extensions::{
+        copy_where_clause, phantom_field_attrs, private_struct_attrs, public_struct_attrs,
+        quote_extensions, require_copy,
+    },
+    states::{CalculateNames, Init, QuoteFields, SplitFieldsByPrivacy, SplitStructAttributes},
+    util::{
+        build_phantom_fields, check_args, derive_names, distribute_attributes, members,
+        parse_options, resolve_self_types, split_attrs, split_fields_by_privacy,
+        wrap_fields_in_parens,
+    },
+};
+use quote::quote;
+use syn::{AttributeArgs, ItemStruct};
+
+fn init(args: AttributeArgs, input: ItemStruct) -> Init {
+    let errors = Errors::default();
+    if input.fields.is_empty() {
+        errors.spanned(input.ident.span(), "struct has no fields");
+    }
+
+    Init {
+        args,
+        input,
+        errors,
+    }
+}
+
+pub fn run(args: AttributeArgs, input: ItemStruct) -> Result<Expansion, Vec<syn::Error>> {
+    init(args, input).finish()
+}
+
+/// Run all steps up to quoting the generated items
+pub fn analyze(args: AttributeArgs, input: ItemStruct) -> QuoteFields {
+    init(args, input).next().next().next().next()
+}
+
+pub trait Intermediate {
+    type Output;
+    fn next(self) -> Self::Output;
+}
+
+pub trait Finishable {
+    fn finish(self) -> Result<Expansion, Vec<syn::Error>>;
+}
+
+impl<S: Intermediate> Finishable for S
+where
+    S::Output: Finishable,
+{
+    fn finish(self) -> Result<Expansion, Vec<syn::Error>> {
+        self.next().finish()
+    }
+}
+
+impl Intermediate for Init {
+    type Output = CalculateNames;
+    fn next(self) -> Self::Output {
+        check_args(&self.args, &self.errors);
+        CalculateNames {
+            names: derive_names(&self.input.ident, &self.args),
+            options: parse_options(&self.args, &self.errors),
+            input: self.input,
+            errors: self.errors,
+        }
+    }
+}
+impl Intermediate for CalculateNames {
+    type Output = SplitStructAttributes;
+    fn next(mut self) -> Self::Output {
+        resolve_self_types(&mut self.input, &self.names.container_name, &self.errors);
+        require_copy(&mut self.input, &self.names, &self.options);
+        SplitStructAttributes {
+            struct_attrs: split_attrs(&self.input.attrs, &self.errors),
+            input: self.input,
+            errors: self.errors,
+            names: self.names,
+            options: self.options,
+        }
+    }
+}
+impl Intermediate for SplitStructAttributes {
+    type Output = SplitFieldsByPrivacy;
+    fn next(self) -> Self::Output {
+        SplitFieldsByPrivacy {
+            fields: distribute_attributes(
+                split_fields_by_privacy(&self.input.fields, &self.options, &self.errors),
+                &self.errors,
+            ),
+            input: self.input,
+            errors: self.errors,
+            names: self.names,
+            options: self.options,
+            struct_attrs: self.struct_attrs,
+        }
+    }
+}
+
+impl Intermediate for SplitFieldsByPrivacy {
+    type Output = QuoteFields;
+    fn next(self) -> Self::Output {
+        let split_fields = self.fields;
+        let Fields {
+            public_fields,
+            private_fields,
+            phantom_fields,
+            ..
+        } = &split_fields;
+
+        let mut public_phantom_fields = phantom_fields.clone();
+        for field in &mut public_phantom_fields {
+            let attrs = phantom_field_attrs(&self.options, &self.struct_attrs, &field.attrs);
+            field.attrs.extend(attrs);
+        }
+        let phantom = build_phantom_fields(public_phantom_fields, self.options.phantom.as_ref());
+
+        let fields = FieldTokenStreams {
+            private_fields: quote! { #(#private_fields,)* },
+            public_fields: quote! { #(#public_fields,)* #phantom },
+        };
+
+        let fields = wrap_fields_in_parens(fields, &self.input.fields);
+
+        QuoteFields {
+            input: self.input,
+            errors: self.errors,
+            names: self.names,
+            options: self.options,
+            struct_attrs: self.struct_attrs,
+            fields,
+            split_fields,
+        }
+    }
+}
+/// Quote a `const` on the container which fails to evaluate if the public variant is not a prefix of the private variant
+///
+/// `public` relies on every public field having the same offset, size and alignment in both variants,
+/// and on the private variant being at least as large and as aligned as the public variant.
+/// The `const` is evaluated right away for containers without generics. For generic containers it can only be
+/// evaluated for concrete parameters, so the check is lazy and only happens once `public` or `public_mut` is used.
+fn quote_layout_assertions(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input: ItemStruct { generics, .. },
+        names:
+            Names {
+                private_name,
+                public_name,
+                container_name,
+                ..
+            },
+        options,
+        split_fields: Fields { public_fields, .. },
+        ..
+    } = state;
+    let krate = &options.crate_path;
+
+    // The layout does not depend on the where clause of the struct, so it is not repeated
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let private = quote!(#private_name #ty_generics);
+    let public = quote!(#public_name #ty_generics);
+    let members = members(public_fields);
+    let eager = generics
+        .params
+        .is_empty()
+        .then(|| quote!(const _: () = #container_name::__SANITIZEABLE_LAYOUT;));
+
+    quote! {
+        #eager
+
+        impl #impl_generics #container_name #ty_generics {
+            #[doc(hidden)]
+            const __SANITIZEABLE_LAYOUT: () = {
+                #(
+                    ::core::assert!(
+                        ::core::mem::offset_of!(#private, #members) == ::core::mem::offset_of!(#public, #members),
+                        ::core::concat!(
+                            "`", ::core::stringify!(#members), "` has a different offset in the public and private variant",
+                        ),
+                    );
+                    ::core::assert!(
+                        #krate::__private::size_of_field(|private: &#private| &private.#members)
+                            == #krate::__private::size_of_field(|public: &#public| &public.#members),
+                        ::core::concat!(
+                            "`", ::core::stringify!(#members), "` has a different size in the public and private variant",
+                        ),
+                    );
+                    ::core::assert!(
+                        #krate::__private::align_of_field(|private: &#private| &private.#members)
+                            == #krate::__private::align_of_field(|public: &#public| &public.#members),
+                        ::core::concat!(
+                            "`", ::core::stringify!(#members), "` has a different alignment in the public and private variant",
+                        ),
+                    );
+                )*
+                ::core::assert!(
+                    ::core::mem::size_of::<#public>() <= ::core::mem::size_of::<#private>(),
+                    "the public variant is larger than the private variant",
+                );
+                ::core::assert!(
+                    ::core::mem::align_of::<#public>() <= ::core::mem::align_of::<#private>(),
+                    "the public variant is more aligned than the private variant",
+                );
+            };
+        }
+    }
+}
+
+/// Quote the `Drop` implementation of the container, which is left out for `Copy` containers
+fn quote_drop(state: &QuoteFields) -> proc_macro2::TokenStream {
+    let QuoteFields {
+        input: ItemStruct { generics, .. },
+        names: Names { container_name, .. },
+        options,
+        ..
+    } = state;
+
+    if options.copy.is_some() {
+        return proc_macro2::TokenStream::new();
+    }
+
+    quote! {
+        impl #generics ::core::ops::Drop for #container_name #generics {
+            /// Safety:
+            /// - Since `private` always contains all fields we can drop the whole structure by dropping `private`
+            /// - We ensure that `Drop` is only run if dropping `self.private` is still our responsibility (see `into_private`)
+            ///
+            /// We can run `core::mem::ManuallyDrop::drop` safely, since `self` can not be accessed after `drop`
+            /// and has not yet been dropped (see above). We can thus ensure that `core::mem::ManuallyDrop::drop` is only
+            /// called once
+            fn drop(&mut self) {
+                unsafe { ::core::mem::ManuallyDrop::drop(&mut self.0.__sanitizeable_private); }
+            }
+        }
+    }
+}
+
+impl Finishable for QuoteFields {
+    fn finish(self) -> Result<Expansion, Vec<syn::Error>> {
+        let layout_assertions = quote_layout_assertions(&self);
+        let drop = quote_drop(&self);
+        let copy_where_clause = copy_where_clause(&self);
+        let extensions = quote_extensions(&self);
+        let extension_private_attrs = private_struct_attrs(&self.options, &self.errors);
+        let extension_public_attrs = public_struct_attrs(&self.options, &self.errors);
+
+        let QuoteFields {
+            input:
+                ItemStruct {
+                    vis,
+                    generics,
+                    semi_token,
+                    ..
+                },
+            names:
+                Names {
+                    private_name,
+                    public_name,
+                    union_name,
+                    container_name,
+                    ..
+                },
+            struct_attrs:
+                Attrs {
+                    private_attrs,
+                    public_attrs,
+                    normal_attrs,
+                    ..
+                },
+            options,
+            fields:
+                FieldTokenStreams {
+                    private_fields,
+                    public_fields,
+                },
+            errors,
+            ..
+        } = self;
+        let krate = &options.crate_path;
+
+        let warnings = errors.finish()?;
+
+        let tokens = quote! {
+            #extension_private_attrs
+            #(#private_attrs)*
+            #(#normal_attrs)*
+            #[repr(C)]
+            #vis struct #private_name #generics #private_fields #semi_token
+
+
+            #extension_public_attrs
+            #(#public_attrs)*
+            #(#normal_attrs)*
+            #[repr(C)]
+            #vis struct #public_name #generics #public_fields  #semi_token
+
+            union #union_name #generics {
+                __sanitizeable_private: ::core::mem::ManuallyDrop<#private_name #generics>,
+                __sanitizeable_public: ::core::mem::ManuallyDrop<#public_name #generics>,
+            }
+
+
+            #[repr(transparent)]
+            #vis struct #container_name #generics (#union_name #generics);
+
+            #layout_assertions
+
+
+            #drop
+
+            impl #generics #krate::Sanitizeable for #container_name #generics #copy_where_clause {
+                type Public = #public_name #generics;
+                type Private = #private_name #generics;
+
+                fn from_private(private: Self::Private) -> Self {
+                    Self(#union_name {
+                        __sanitizeable_private: ::core::mem::ManuallyDrop::new(private),
+                    })
+                }
+                /// Safety:
+                /// - We ensure that `std::mem::ManuallyDrop` has not yet been dropped (see `into_private` and `impl Drop`)
+                /// - The fields of `public` are a strict subset of `private` and are in the same order.
+                /// - The fields of `public` have the same offsets in `private` (checked by `__SANITIZEABLE_LAYOUT`)
+                ///
+                /// It is thus safe to access and modify `public` without invalidating `private`
+                fn public(&self) -> &Self::Public {
+                    let () = Self::__SANITIZEABLE_LAYOUT;
+                    unsafe { &*self.0.__sanitizeable_public }
+                }
+                /// Safety:
+                /// see `public`
+                fn public_mut(&mut self) -> &mut Self::Public {
+                    let () = Self::__SANITIZEABLE_LAYOUT;
+                    unsafe { &mut *self.0.__sanitizeable_public }
+                }
+                /// Safety:
+                /// - We ensure that `std::mem::ManuallyDrop` has not yet been dropped (see `into_private` and `impl Drop`)
+                /// - The fields of `public` are a strict subset of `private` and are in the same order.
+                ///
+                /// It is thus safe to access and modify `private` without invalidating `public`
+                fn private(&self) -> &Self::Private {
+                    unsafe { &*self.0.__sanitizeable_private }
+                }
+                /// Safety:
+                /// see `private`
+                fn private_mut(&mut self) -> &mut Self::Private {
+                    unsafe { &mut *self.0.__sanitizeable_private }
+                }
+                /// Safety:
+                /// - `std::mem::ManuallyDrop::drop` has not yet been called, since self still exists
+                ///     -> We can call `std::mem::ManuallyDrop::into_inner`
+                ///     - we `core::mem::forget(self);` to make sure that `Drop` does not run and drop `private` twice
+                /// - `Self` is `#[repr(transparent)]` which makes it safe to cast to it's inner value
+                // `Copy` containers do not implement `Drop`, so forgetting them does nothing
+                #[allow(clippy::forget_non_drop)]
+                fn into_private(self) -> Self::Private {
+                    let inner = unsafe {
+                        let ptr = &self
+                            as *const #container_name #generics
+                            as *const #union_name     #generics;
+
+
+                        // Read the inner value ("cast" `self` to `#union_name`)
+                        let value = ptr.read();
+
+                        // `core::mem::forget(self)` to skip running it's `Drop` implementation
+                        // This is done after the `ptr.read()` to ensure that the data pointed to by `ptr`
+                        // is valid during the read
+                        ::core::mem::forget(self);
+
+                        value
+                    };
+                    ::core::mem::ManuallyDrop::into_inner(unsafe { inner.__sanitizeable_private })
+                }
+            }
+
+            #extensions
+        };
+
+        Ok(Expansion { tokens, warnings })
+    }
+}
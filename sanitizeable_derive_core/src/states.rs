@@ -1,32 +1,42 @@
-use crate::datatypes::{Attrs, FieldTokenStreams, Fields, Names};
+use crate::datatypes::{Attrs, Errors, FieldTokenStreams, Fields, Names, Options};
 use syn::{AttributeArgs, ItemStruct};
 
 pub struct Init {
     pub args: AttributeArgs,
     pub input: ItemStruct,
+    pub errors: Errors,
 }
 
 pub struct CalculateNames {
     pub input: ItemStruct,
+    pub errors: Errors,
     pub names: Names,
+    pub options: Options,
 }
 
 pub struct SplitStructAttributes {
     pub input: ItemStruct,
+    pub errors: Errors,
     pub names: Names,
+    pub options: Options,
     pub struct_attrs: Attrs,
 }
 
 pub struct SplitFieldsByPrivacy {
     pub input: ItemStruct,
+    pub errors: Errors,
     pub names: Names,
+    pub options: Options,
     pub struct_attrs: Attrs,
     pub fields: Fields,
 }
 
 pub struct QuoteFields {
     pub input: ItemStruct,
+    pub errors: Errors,
     pub names: Names,
+    pub options: Options,
     pub struct_attrs: Attrs,
     pub fields: FieldTokenStreams,
+    pub split_fields: Fields,
 }
@@ -0,0 +1,1027 @@
+use crate::datatypes::{
+    Attrs, Classification, Errors, FieldTokenStreams, Fields, Names, Options, PrivateOptions,
+};
+use proc_macro2::Span;
+use quote::{quote, ToTokens};
+use syn::parse_quote;
+use syn::{
+    ext::IdentExt,
+    visit_mut::{self, VisitMut},
+    Attribute, Field, FieldsNamed, FieldsUnnamed, GenericParam, Generics, Ident, Index, Lit,
+    LitStr, Member, Meta, MetaNameValue, NestedMeta, WhereClause, WherePredicate,
+};
+
+/// Arguments of `#[sanitizeable(...)]` which name one of the generated types, like `public_name = "..."`
+const NAME_ARGS: &[&str] = &[
+    "public_name",
+    "private_name",
+    "container_name",
+    "union_name",
+    "sealed_name",
+];
+
+/// Other arguments of `#[sanitizeable(...)]` which take a string
+const STRING_ARGS: &[&str] = &["crate", "diesel_table", "phantom"];
+
+/// Arguments of `#[sanitizeable(...)]` which are bare flags, like `seal`
+const FLAG_ARGS: &[&str] = &[
+    "seal",
+    "sign",
+    "erase",
+    "catalogue",
+    "view_sql",
+    "json_schema",
+    "private_json_schema",
+    "typescript",
+    "openapi",
+    "graphql",
+    "axum",
+    "sqlx",
+    "valuable",
+    "log",
+    "copy",
+    "const_fn",
+    "accessors",
+];
+
+/// Attributes which could change the layout of a single variant, breaking the guarantee that
+/// the fields of the public variant are a prefix of the private variant
+const LAYOUT_ATTRS: &[&str] = &["cfg", "cfg_attr", "repr"];
+
+/// Prefixes which apply an attribute to only some of the generated types
+const ATTR_PREFIXES: &[&str] = &["public_attr", "private_attr", "phantom_attr", "sealed_attr"];
+
+/// The number of single character edits needed to turn `a` into `b`
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a != *b);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Describe the unknown `kind` called `name`, suggesting the closest of `candidates` if it looks like a typo
+fn unknown(kind: &str, name: &str, candidates: &[&str]) -> String {
+    let closest = candidates
+        .iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .min();
+
+    match closest {
+        Some((distance, candidate)) if distance <= name.len().max(3) / 3 => {
+            format!("unknown {kind} `{name}`, did you mean `{candidate}`?")
+        }
+        _ => {
+            let candidates = candidates
+                .iter()
+                .map(|candidate| format!("`{candidate}`"))
+                .collect::<Vec<_>>();
+            format!(
+                "unknown {kind} `{name}`, expected one of {}",
+                candidates.join(", ")
+            )
+        }
+    }
+}
+
+pub fn attr_is_private(attr: &Attribute) -> bool {
+    attr.path.segments.first().unwrap().ident == "private"
+}
+
+pub fn is_private(field: &Field) -> bool {
+    field.attrs.iter().any(attr_is_private)
+}
+
+fn parse_private_options(field: &Field, errors: &Errors) -> PrivateOptions {
+    let mut options = PrivateOptions {
+        erase_with: None,
+        graphql_guard: None,
+    };
+
+    for attr in field.attrs.iter().filter(|attr| attr_is_private(attr)) {
+        let nested = match attr.parse_meta() {
+            Ok(Meta::List(list)) => list.nested,
+            Ok(Meta::Path(_)) => continue,
+            Ok(meta @ Meta::NameValue(_)) => {
+                errors.push(syn::Error::new_spanned(
+                    meta,
+                    "expected #[private] or #[private(...)]",
+                ));
+                continue;
+            }
+            Err(error) => {
+                errors.push(error);
+                continue;
+            }
+        };
+
+        for meta in nested {
+            match meta {
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(string),
+                    ..
+                })) if path.is_ident("erase_with") => match string.parse() {
+                    Ok(path) => options.erase_with = Some(path),
+                    Err(error) => errors.spanned(
+                        string.span(),
+                        format!("expected a path to a function: {error}"),
+                    ),
+                },
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(string),
+                    ..
+                })) if path.is_ident("graphql_guard") => options.graphql_guard = Some(string),
+                other => errors.push(syn::Error::new_spanned(
+                    other,
+                    "expected `erase_with = \"...\"` or `graphql_guard = \"...\"`",
+                )),
+            }
+        }
+    }
+
+    options
+}
+
+/// `#[classify(...)]` is always read, since the audit manifest is requested through the environment
+/// rather than an option of the struct, so other derives can not use an attribute of that name
+pub fn attr_is_classify(attr: &Attribute) -> bool {
+    attr.path.is_ident("classify")
+}
+
+/// Parse and remove all `#[classify(LABEL, ..., retention = "...")]` attributes of `field`
+fn take_classification(field: &mut Field, errors: &Errors) -> Classification {
+    let mut classification = Classification {
+        labels: Vec::new(),
+        retention: None,
+    };
+
+    for attr in field.attrs.iter().filter(|attr| attr_is_classify(attr)) {
+        let Ok(Meta::List(list)) = attr.parse_meta() else {
+            errors.spanned(
+                attr.path.segments[0].ident.span(),
+                "expected #[classify(LABEL, ..., retention = \"...\")]",
+            );
+            continue;
+        };
+
+        for meta in list.nested {
+            match meta {
+                NestedMeta::Meta(Meta::Path(path)) => {
+                    let label = path
+                        .segments
+                        .iter()
+                        .map(|segment| segment.ident.to_string());
+                    classification
+                        .labels
+                        .push(label.collect::<Vec<_>>().join("::"));
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(string),
+                    ..
+                })) if path.is_ident("retention") => classification.retention = Some(string),
+                other => errors.push(syn::Error::new_spanned(
+                    other,
+                    "expected a label or `retention = \"...\"`",
+                )),
+            }
+        }
+    }
+
+    field.attrs.retain(|attr| !attr_is_classify(attr));
+    classification
+}
+
+pub fn attr_is_column(attr: &Attribute) -> bool {
+    attr.path.is_ident("column")
+}
+
+/// Whether an enabled extension reads `#[column = "..."]`, which is left to other derives otherwise
+pub fn reads_columns(options: &Options) -> bool {
+    options.view_sql.is_some() || options.sqlx.is_some() || options.diesel_table.is_some()
+}
+
+/// Parse and remove the `#[column = "..."]` attribute of `field` if an extension reads it,
+/// returning the name of its database column
+fn take_column(field: &mut Field, index: usize, options: &Options, errors: &Errors) -> String {
+    let name = field
+        .ident
+        .as_ref()
+        .map_or_else(|| index.to_string(), |ident| ident.unraw().to_string());
+    if !reads_columns(options) {
+        return name;
+    }
+
+    let mut column = None;
+    for attr in field.attrs.iter().filter(|attr| attr_is_column(attr)) {
+        if let Ok(Meta::NameValue(MetaNameValue {
+            lit: Lit::Str(string),
+            ..
+        })) = attr.parse_meta()
+        {
+            column = Some(string.value());
+        } else {
+            errors.spanned(
+                attr.path.segments[0].ident.span(),
+                "expected #[column = \"...\"]",
+            );
+        }
+    }
+
+    field.attrs.retain(|attr| !attr_is_column(attr));
+    column.unwrap_or(name)
+}
+
+fn remove_private_attrs(mut field: Field) -> Field {
+    field.attrs.retain(|attr| !attr_is_private(attr));
+    field
+}
+
+/// The path of an attribute after its `prefix`, e.g. `derive` for `#[public_attr::derive(...)]`
+pub fn build_remaining_attr(
+    prefix: &Ident,
+    segments: syn::punctuated::Iter<syn::PathSegment>,
+    errors: &Errors,
+) -> Option<syn::punctuated::Punctuated<syn::PathSegment, syn::Token!(::)>> {
+    let Some(last_segment) = segments.clone().next_back() else {
+        errors.spanned(
+            prefix.span(),
+            format!("expected an attribute after `{prefix}::`, e.g. #[{prefix}::derive(Clone)]"),
+        );
+        return None;
+    };
+    let last_segment = &last_segment.ident;
+    let name = last_segment.to_string();
+    if LAYOUT_ATTRS.contains(&name.as_str()) {
+        errors.spanned(
+            last_segment.span(),
+            format!(
+                "You may not use #[{name}(...)] in an attribute that is only applied to some variants"
+            ),
+        );
+    }
+
+    Some(segments.cloned().collect())
+}
+
+pub fn split_attrs(attrs: &[Attribute], errors: &Errors) -> Attrs {
+    let mut private_attrs = Vec::new();
+    let mut public_attrs = Vec::new();
+    let mut normal_attrs = Vec::new();
+    let mut phantom_attrs = Vec::new();
+    let mut sealed_attrs = Vec::new();
+
+    for mut attr in attrs.iter().cloned() {
+        let mut segments = attr.path.segments.iter();
+        if let Some(first) = segments.next() {
+            if first.ident == "private_attr" {
+                if let Some(segments) = build_remaining_attr(&first.ident, segments, errors) {
+                    attr.path.segments = segments;
+                    private_attrs.push(attr);
+                }
+                continue;
+            }
+
+            if first.ident == "public_attr" {
+                if let Some(segments) = build_remaining_attr(&first.ident, segments, errors) {
+                    attr.path.segments = segments;
+                    public_attrs.push(attr);
+                }
+                continue;
+            }
+
+            if first.ident == "phantom_attr" {
+                if let Some(segments) = build_remaining_attr(&first.ident, segments, errors) {
+                    attr.path.segments = segments;
+                    phantom_attrs.push(attr);
+                }
+                continue;
+            }
+
+            if first.ident == "sealed_attr" {
+                if let Some(segments) = build_remaining_attr(&first.ident, segments, errors) {
+                    attr.path.segments = segments;
+                    sealed_attrs.push(attr);
+                }
+                continue;
+            }
+
+            // Applying a misspelled prefix like `publik_attr::` to all variants would be surprising
+            let prefix = first.ident.to_string();
+            if prefix.ends_with("_attr") && segments.len() > 0 {
+                errors.spanned(
+                    first.ident.span(),
+                    unknown("attribute prefix", &prefix, ATTR_PREFIXES),
+                );
+                continue;
+            }
+        }
+
+        core::mem::drop(segments);
+
+        normal_attrs.push(attr);
+    }
+
+    Attrs {
+        private_attrs,
+        public_attrs,
+        normal_attrs,
+        phantom_attrs: Some(phantom_attrs),
+        sealed_attrs,
+    }
+}
+
+macro_rules! name_attr {
+    ($input: ident, $attrs: ident, $key: literal, $otherwise: literal) => {{
+        let expected_ident = Ident::new($key, Span::call_site());
+        // Check if the attr path is an assigment that has only $key as it's path
+        // and return its string value (if it has one)
+        $attrs
+            .iter()
+            .find_map(|attr| match attr.clone() {
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path:
+                        syn::Path {
+                            leading_colon: None,
+                            segments,
+                        },
+                    lit: Lit::Str(string),
+                    ..
+                })) if segments
+                    .iter()
+                    .map(|segment| &segment.ident)
+                    .collect::<Vec<_>>()
+                    == vec![&expected_ident] =>
+                {
+                    string.parse::<Ident>().ok()
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| Ident::new(&format!($otherwise, $input), $input.span()))
+    }};
+}
+
+pub fn derive_names(input: &Ident, attrs: &[NestedMeta]) -> Names {
+    Names {
+        container_name: name_attr!(input, attrs, "container_name", "{}"),
+        private_name: name_attr!(input, attrs, "private_name", "{}Private"),
+        public_name: name_attr!(input, attrs, "public_name", "{}Public"),
+        union_name: name_attr!(input, attrs, "union_name", "{}Union"),
+        sealed_name: name_attr!(input, attrs, "sealed_name", "{}Sealed"),
+    }
+}
+
+/// Whether `attr` is only understood by `#[sanitizeable]`, like `#[private]` or `#[public_attr::derive(Clone)]`
+fn is_helper_attr(attr: &Attribute) -> bool {
+    let segments = &attr.path.segments;
+    let first = segments[0].ident.to_string();
+
+    attr_is_private(attr)
+        || attr_is_classify(attr)
+        || attr_is_column(attr)
+        || ATTR_PREFIXES.contains(&first.as_str())
+        || (first.ends_with("_attr") && segments.len() > 1)
+}
+
+/// The input struct named like `name`, without the attributes only understood by `#[sanitizeable]`
+fn fallback_struct(input: &syn::ItemStruct, name: &Ident) -> syn::ItemStruct {
+    let mut input = input.clone();
+    input.ident = name.clone();
+    input.attrs.retain(|attr| !is_helper_attr(attr));
+    for field in &mut input.fields {
+        field.attrs.retain(|attr| !is_helper_attr(attr));
+    }
+    input
+}
+
+/// Stand-ins for the variants and the container, which implements `Sanitizeable` without being usable
+pub fn fallback_items(args: &[NestedMeta], input: &syn::ItemStruct) -> proc_macro2::TokenStream {
+    let Names {
+        private_name,
+        public_name,
+        container_name,
+        ..
+    } = derive_names(&input.ident, args);
+    let krate = crate_path(args, &Errors::default());
+    let vis = &input.vis;
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let private = fallback_struct(input, &private_name);
+    let public = fallback_struct(input, &public_name);
+
+    quote! {
+        #private
+        #public
+
+        #vis struct #container_name #generics (::core::marker::PhantomData<#private_name #ty_generics>) #where_clause;
+
+        impl #impl_generics #krate::Sanitizeable for #container_name #ty_generics #where_clause {
+            type Public = #public_name #ty_generics;
+            type Private = #private_name #ty_generics;
+
+            fn from_private(_: Self::Private) -> Self {
+                ::core::unreachable!()
+            }
+            fn public(&self) -> &Self::Public {
+                ::core::unreachable!()
+            }
+            fn public_mut(&mut self) -> &mut Self::Public {
+                ::core::unreachable!()
+            }
+            fn private(&self) -> &Self::Private {
+                ::core::unreachable!()
+            }
+            fn private_mut(&mut self) -> &mut Self::Private {
+                ::core::unreachable!()
+            }
+            fn into_private(self) -> Self::Private {
+                ::core::unreachable!()
+            }
+        }
+    }
+}
+
+/// Report all arguments of `#[sanitizeable(...)]` which are unknown or have the wrong form
+pub fn check_args(args: &[NestedMeta], errors: &Errors) {
+    let known = [NAME_ARGS, STRING_ARGS, FLAG_ARGS].concat();
+    let mut seen = Vec::new();
+
+    for arg in args {
+        let NestedMeta::Meta(meta) = arg else {
+            errors.push(syn::Error::new_spanned(
+                arg,
+                "expected an argument like `seal` or `public_name = \"...\"`",
+            ));
+            continue;
+        };
+
+        let path = meta.path();
+        let name = pretty_tokens(path);
+        if !known.contains(&name.as_str()) {
+            errors.push(syn::Error::new_spanned(
+                path,
+                unknown("argument", &name, &known),
+            ));
+            continue;
+        }
+
+        if seen.contains(&name) {
+            errors.push(syn::Error::new_spanned(
+                path,
+                format!("`{name}` is given more than once"),
+            ));
+            continue;
+        }
+        seen.push(name.clone());
+
+        let takes_string = !FLAG_ARGS.contains(&name.as_str());
+        match meta {
+            Meta::Path(_) if !takes_string => {}
+            Meta::NameValue(MetaNameValue { lit, .. }) if takes_string => match lit {
+                Lit::Str(string) if NAME_ARGS.contains(&name.as_str()) => {
+                    if string.parse::<Ident>().is_err() {
+                        errors.spanned(string.span(), "expected an identifier");
+                    }
+                }
+                Lit::Str(_) => {}
+                _ => errors.spanned(lit.span(), "expected a string literal"),
+            },
+            _ if takes_string => errors.push(syn::Error::new_spanned(
+                meta,
+                format!("expected `{name} = \"...\"`"),
+            )),
+            _ => errors.push(syn::Error::new_spanned(
+                meta,
+                format!("`{name}` does not take a value"),
+            )),
+        }
+    }
+}
+
+/// Find a bare flag like `seal` in the macro arguments and return its span
+fn flag_attr(attrs: &[NestedMeta], key: &str) -> Option<Span> {
+    attrs.iter().find_map(|attr| match attr {
+        NestedMeta::Meta(Meta::Path(path)) if path.is_ident(key) => {
+            Some(path.segments[0].ident.span())
+        }
+        _ => None,
+    })
+}
+
+/// Find a string argument like `diesel_table = "..."` in the macro arguments
+fn string_attr(attrs: &[NestedMeta], key: &str) -> Option<LitStr> {
+    attrs.iter().find_map(|attr| match attr {
+        NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+            path,
+            lit: Lit::Str(string),
+            ..
+        })) if path.is_ident(key) => Some(string.clone()),
+        _ => None,
+    })
+}
+
+/// The path given in `crate = "..."`, or `::sanitizeable`
+fn crate_path(attrs: &[NestedMeta], errors: &Errors) -> syn::Path {
+    let Some(path) = string_attr(attrs, "crate") else {
+        return parse_quote!(::sanitizeable);
+    };
+
+    path.parse().unwrap_or_else(|error| {
+        errors.spanned(
+            path.span(),
+            format!("expected a path to the `sanitizeable` crate: {error}"),
+        );
+        parse_quote!(::sanitizeable)
+    })
+}
+
+/// The type given in `phantom = "..."`
+fn phantom_type(attrs: &[NestedMeta], errors: &Errors) -> Option<syn::Type> {
+    let phantom = string_attr(attrs, "phantom")?;
+
+    phantom
+        .parse()
+        .map_err(|error| errors.spanned(phantom.span(), format!("expected a type: {error}")))
+        .ok()
+}
+
+pub fn parse_options(attrs: &[NestedMeta], errors: &Errors) -> Options {
+    Options {
+        crate_path: crate_path(attrs, errors),
+        seal: flag_attr(attrs, "seal"),
+        sign: flag_attr(attrs, "sign"),
+        erase: flag_attr(attrs, "erase"),
+        json_schema: flag_attr(attrs, "json_schema"),
+        private_json_schema: flag_attr(attrs, "private_json_schema"),
+        typescript: flag_attr(attrs, "typescript"),
+        openapi: flag_attr(attrs, "openapi"),
+        graphql: flag_attr(attrs, "graphql"),
+        axum: flag_attr(attrs, "axum"),
+        sqlx: flag_attr(attrs, "sqlx"),
+        valuable: flag_attr(attrs, "valuable"),
+        log: flag_attr(attrs, "log"),
+        copy: flag_attr(attrs, "copy"),
+        catalogue: flag_attr(attrs, "catalogue"),
+        view_sql: flag_attr(attrs, "view_sql"),
+        const_fn: flag_attr(attrs, "const_fn"),
+        accessors: flag_attr(attrs, "accessors"),
+        diesel_table: string_attr(attrs, "diesel_table"),
+        phantom: phantom_type(attrs, errors),
+    }
+}
+
+/// Types which give access to the values of their type arguments, e.g. `Vec<T>` or `Option<T>`
+const HOLDING_TYPES: &[&str] = &[
+    "Box",
+    "Vec",
+    "VecDeque",
+    "LinkedList",
+    "BinaryHeap",
+    "HashMap",
+    "BTreeMap",
+    "HashSet",
+    "BTreeSet",
+    "Option",
+    "Result",
+    "Rc",
+    "Arc",
+    "Cow",
+    "Cell",
+    "RefCell",
+    "Mutex",
+    "RwLock",
+];
+
+/// Replaces `Self` and the name of the annotated struct in types with the container type,
+/// or reports them if a public field would hold the container
+struct ResolveSelf<'a> {
+    original: &'a Ident,
+    container_name: &'a Ident,
+    container: syn::Type,
+    public: bool,
+    /// Whether the type being visited is held by the field, as opposed to e.g. the `T` of `PhantomData<T>`
+    holding: bool,
+    errors: &'a Errors,
+}
+
+impl ResolveSelf<'_> {
+    /// Whether a mention of the struct at the current position has to be reported
+    fn report_public(&self, span: Span, name: &dyn std::fmt::Display) -> bool {
+        if self.public && self.holding {
+            self.errors.spanned(
+                span,
+                format!(
+                    "a public field can not hold `{name}`, since the public variant would give access to \
+                     the private fields of the nested value, consider marking the field #[private]"
+                ),
+            );
+        }
+        self.public && self.holding
+    }
+
+    /// Whether the type arguments of `ty` are held by the field if `ty` is
+    fn holds_arguments(ty: &syn::Type) -> bool {
+        match ty {
+            syn::Type::Path(syn::TypePath { qself: None, path }) => {
+                let last = path.segments.last().expect("empty type path");
+                HOLDING_TYPES.contains(&last.ident.to_string().as_str())
+            }
+            syn::Type::Array(_)
+            | syn::Type::Group(_)
+            | syn::Type::Paren(_)
+            | syn::Type::Ptr(_)
+            | syn::Type::Reference(_)
+            | syn::Type::Slice(_)
+            | syn::Type::Tuple(_) => true,
+            _ => false,
+        }
+    }
+}
+
+impl VisitMut for ResolveSelf<'_> {
+    fn visit_type_mut(&mut self, ty: &mut syn::Type) {
+        if let syn::Type::Path(syn::TypePath { qself: None, path }) = ty {
+            if path.is_ident("Self") {
+                if !self.report_public(path.segments[0].ident.span(), &"Self") {
+                    *ty = self.container.clone();
+                }
+                return;
+            }
+
+            if path.leading_colon.is_none() && path.segments.len() == 1 {
+                let ident = &mut path.segments[0].ident;
+                if (ident == self.original || ident == self.container_name)
+                    && !self.report_public(ident.span(), &ident.clone())
+                {
+                    let span = ident.span();
+                    *ident = self.container_name.clone();
+                    ident.set_span(span);
+                }
+            }
+        }
+
+        let holding = self.holding;
+        self.holding = holding && Self::holds_arguments(ty);
+        visit_mut::visit_type_mut(self, ty);
+        self.holding = holding;
+    }
+
+    fn visit_path_mut(&mut self, path: &mut syn::Path) {
+        if path.leading_colon.is_none()
+            && path.segments.len() > 1
+            && path.segments[0].ident == "Self"
+        {
+            self.errors.spanned(
+                path.segments[0].ident.span(),
+                "`Self::...` can not be used in the type of a field, name the associated type with `<Self as Trait>::...`",
+            );
+        }
+        visit_mut::visit_path_mut(self, path);
+    }
+}
+
+/// Make `Self` and the name of the struct refer to the container in the types of all fields
+///
+/// `Self` would otherwise mean a different type in each variant, e.g. `Vec<Box<Self>>` would be
+/// `Vec<Box<TestPrivate>>` and `Vec<Box<TestPublic>>`, which can not be reinterpreted as each other.
+/// Public fields can not hold the struct, e.g. through a `Vec` or a reference: holding containers would
+/// make the private fields of the nested values reachable from the public variant, and holding public variants
+/// would not match the layout of the private variant, since `TestPublic` is smaller than `Test`.
+/// Other types like `PhantomData<Self>` or a typed id `Id<Test>` can not be checked and refer to the container.
+pub fn resolve_self_types(input: &mut syn::ItemStruct, container_name: &Ident, errors: &Errors) {
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let container: syn::Type = parse_quote!(#container_name #ty_generics);
+
+    for field in &mut input.fields {
+        ResolveSelf {
+            original: &input.ident,
+            container_name,
+            container: container.clone(),
+            public: !is_private(field),
+            holding: true,
+            errors,
+        }
+        .visit_type_mut(&mut field.ty);
+    }
+}
+
+/// The expressions used to access `fields` on a struct that only contains `fields` in that order
+pub fn members(fields: &[Field]) -> Vec<Member> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(i)),
+        })
+        .collect()
+}
+
+pub fn field_with_attrs(mut field: Field, mut attrs: Vec<Vec<Attribute>>) -> Field {
+    field.attrs = attrs.pop().unwrap_or_default();
+    for attr_args in attrs {
+        field.attrs.extend(attr_args);
+    }
+    field
+}
+
+pub fn split_fields_by_privacy(fields: &syn::Fields, options: &Options, errors: &Errors) -> Fields {
+    let mut private_fields = Vec::new();
+    let mut public_fields = Vec::new();
+    let mut private_options = Vec::new();
+    let mut public_classifications = Vec::new();
+    let mut private_classifications = Vec::new();
+    let mut public_columns = Vec::new();
+    let mut private_columns = Vec::new();
+
+    for (index, mut field) in fields.clone().into_iter().enumerate() {
+        let classification = take_classification(&mut field, errors);
+        let column = take_column(&mut field, index, options, errors);
+        if is_private(&field) {
+            private_classifications.push(classification);
+            private_columns.push(column);
+            private_options.push(parse_private_options(&field, errors));
+            private_fields.push(remove_private_attrs(field));
+        } else {
+            public_classifications.push(classification);
+            public_columns.push(column);
+            public_fields.push(field);
+        }
+    }
+
+    public_classifications.extend(private_classifications);
+    public_columns.extend(private_columns);
+
+    Fields {
+        private_fields,
+        public_fields,
+        phantom_fields: vec![],
+        sealed_fields: vec![],
+        private_options,
+        classifications: public_classifications,
+        columns: public_columns,
+    }
+}
+
+pub fn distribute_attributes(fields: Fields, errors: &Errors) -> Fields {
+    let mut private_fields: Vec<Field> = Vec::new();
+    let mut public_fields: Vec<Field> = Vec::new();
+    let mut phantom_fields: Vec<Field> = Vec::new();
+    let mut sealed_fields: Vec<Field> = Vec::new();
+
+    for field in fields.public_fields {
+        let attrs = split_attrs(&field.attrs, errors);
+
+        sealed_fields.push(field_with_attrs(
+            field.clone(),
+            vec![attrs.sealed_attrs, attrs.normal_attrs.clone()],
+        ));
+        public_fields.push(field_with_attrs(
+            field.clone(),
+            vec![attrs.public_attrs, attrs.normal_attrs.clone()],
+        ));
+        private_fields.push(field_with_attrs(
+            field,
+            vec![attrs.private_attrs.clone(), attrs.normal_attrs.clone()],
+        ));
+    }
+
+    for field in fields.private_fields {
+        let attrs = split_attrs(&field.attrs, errors);
+
+        phantom_fields.push(field_with_attrs(
+            field.clone(),
+            vec![attrs.phantom_attrs.unwrap()],
+        ));
+        private_fields.push(field_with_attrs(
+            field,
+            vec![attrs.private_attrs, attrs.normal_attrs],
+        ));
+    }
+
+    if phantom_fields.is_empty() {
+        errors.warn(Span::call_site(), "struct has no private fields");
+    }
+
+    Fields {
+        private_fields,
+        public_fields,
+        phantom_fields,
+        sealed_fields,
+        private_options: fields.private_options,
+        classifications: fields.classifications,
+        columns: fields.columns,
+    }
+}
+
+/// Replaces `_` in the type given in `phantom = "..."` with the type of a private field
+struct ReplaceInfer<'a>(&'a syn::Type);
+
+impl VisitMut for ReplaceInfer<'_> {
+    fn visit_type_mut(&mut self, ty: &mut syn::Type) {
+        if let syn::Type::Infer(_) = ty {
+            *ty = self.0.clone();
+        } else {
+            visit_mut::visit_type_mut(self, ty);
+        }
+    }
+}
+
+/// Whether the struct derives serde's `Serialize` or `Deserialize`, which makes `#[serde(...)]` usable on its fields
+pub fn derives_serde(struct_attrs: &Attrs) -> bool {
+    struct_attrs
+        .public_attrs
+        .iter()
+        .chain(&struct_attrs.normal_attrs)
+        .filter(|attr| attr.path.is_ident("derive"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .any(|nested| match nested {
+            NestedMeta::Meta(Meta::Path(path)) => path.segments.last().is_some_and(|segment| {
+                segment.ident == "Serialize" || segment.ident == "Deserialize"
+            }),
+            _ => false,
+        })
+}
+
+/// Whether `attrs` already contain a `#[serde(skip...)]` attribute
+pub fn skips_serde(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("serde"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .any(|nested| match nested {
+            NestedMeta::Meta(Meta::Path(path)) => path
+                .get_ident()
+                .is_some_and(|ident| ident.to_string().starts_with("skip")),
+            _ => false,
+        })
+}
+
+pub fn build_phantom_fields(
+    phantom_fields: Vec<Field>,
+    phantom: Option<&syn::Type>,
+) -> proc_macro2::TokenStream {
+    if phantom_fields.is_empty() {
+        proc_macro2::TokenStream::new()
+    } else {
+        let mut attrs = Vec::new();
+        let mut names = Vec::new();
+        let mut types = Vec::new();
+        for field in phantom_fields {
+            attrs.push(field.attrs);
+            names.push(if let Some(ident) = field.ident {
+                let ident = Ident::new(&format!("_{}", ident.unraw()), ident.span());
+                quote! {#ident: }
+            } else {
+                proc_macro2::TokenStream::new()
+            });
+            types.push(match phantom {
+                Some(phantom) => {
+                    let mut phantom = phantom.clone();
+                    ReplaceInfer(&field.ty).visit_type_mut(&mut phantom);
+                    phantom
+                }
+                None => field.ty,
+            });
+        }
+        quote! {
+            #(#(#attrs)* #names ::core::marker::PhantomData<#types>,)*
+        }
+    }
+}
+
+/// The members of the `PhantomData` fields built by `build_phantom_fields`,
+/// which come after `offset` other fields
+pub fn phantom_members(phantom_fields: &[Field], offset: usize) -> Vec<Member> {
+    phantom_fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => Member::Named(Ident::new(&format!("_{}", ident.unraw()), ident.span())),
+            None => Member::Unnamed(Index::from(offset + i)),
+        })
+        .collect()
+}
+
+/// Render `tokens` as they would usually be written, e.g. `Option<(u8, u8)>` instead of `Option < (u8 , u8) >`
+pub fn pretty_tokens(tokens: &impl ToTokens) -> String {
+    let tokens = tokens.to_token_stream().to_string();
+    let is_word = |c: char| c.is_alphanumeric() || c == '_' || c == '\'';
+
+    let chars = tokens.chars().collect::<Vec<_>>();
+    let mut out = String::with_capacity(tokens.len());
+    let mut in_string = false;
+
+    for (i, &c) in chars.iter().enumerate() {
+        if in_string {
+            out.push(c);
+            in_string = !(c == '"' && chars[i - 1] != '\\');
+            continue;
+        }
+
+        if c == ' ' {
+            let prev = out.chars().next_back().unwrap_or(' ');
+            let next = chars.get(i + 1).copied().unwrap_or(' ');
+            let arrow = (prev == '>' && out.ends_with("->"))
+                || (next == '-' && chars.get(i + 2) == Some(&'>'));
+
+            if (is_word(prev) && is_word(next))
+                || prev == ','
+                || prev == '='
+                || next == '='
+                || arrow
+            {
+                out.push(' ');
+            }
+        } else {
+            in_string = c == '"';
+            out.push(c);
+        }
+    }
+
+    out
+}
+
+/// Append `predicates` to the where clause of `generics`
+pub fn extend_where_clause(
+    generics: &Generics,
+    predicates: impl IntoIterator<Item = WherePredicate>,
+) -> WhereClause {
+    let mut where_clause = generics
+        .where_clause
+        .clone()
+        .unwrap_or_else(|| WhereClause {
+            where_token: syn::token::Where::default(),
+            predicates: syn::punctuated::Punctuated::new(),
+        });
+    where_clause.predicates.extend(predicates);
+    where_clause
+}
+
+/// Add `params` to `generics`, keeping lifetimes in front of all other parameters
+pub fn extend_generics(
+    generics: &Generics,
+    params: impl IntoIterator<Item = GenericParam>,
+) -> Generics {
+    let mut generics = generics.clone();
+    for param in params {
+        if let GenericParam::Lifetime(_) = param {
+            generics.params.insert(0, param);
+        } else {
+            generics.params.push(param);
+        }
+    }
+    generics
+}
+
+pub fn wrap_fields_in_parens(
+    fields: FieldTokenStreams,
+    input_fields: &syn::Fields,
+) -> FieldTokenStreams {
+    let FieldTokenStreams {
+        private_fields,
+        public_fields,
+    } = fields;
+
+    let mut out = FieldTokenStreams {
+        private_fields: proc_macro2::TokenStream::new(),
+        public_fields: proc_macro2::TokenStream::new(),
+    };
+
+    match &input_fields {
+        syn::Fields::Named(FieldsNamed { brace_token, .. }) => {
+            brace_token.surround(&mut out.private_fields, |f| *f = private_fields);
+            brace_token.surround(&mut out.public_fields, |f| *f = public_fields);
+        }
+        syn::Fields::Unnamed(FieldsUnnamed { paren_token, .. }) => {
+            paren_token.surround(&mut out.private_fields, |f| *f = private_fields);
+            paren_token.surround(&mut out.public_fields, |f| *f = public_fields);
+        }
+        syn::Fields::Unit => {
+            assert!(private_fields.is_empty());
+            assert!(public_fields.is_empty());
+        }
+    }
+
+    out
+}
@@ -0,0 +1,81 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use sanitizeable_derive_core::expand;
+
+/// Expand `#[sanitizeable(args)] item` and format the result like `cargo expand` would
+fn expand_pretty(args: TokenStream, item: TokenStream) -> String {
+    let tokens = expand(args, item).unwrap_or_else(|errors| {
+        let errors = errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+        panic!("expansion failed: {:?}", errors)
+    });
+    prettyplease::unparse(&syn2::parse2(tokens).unwrap())
+}
+
+fn expand_errors(args: TokenStream, item: TokenStream) -> Vec<String> {
+    match expand(args, item) {
+        Ok(_) => panic!("expansion succeeded"),
+        Err(errors) => errors.iter().map(ToString::to_string).collect(),
+    }
+}
+
+#[test]
+fn named_fields() {
+    insta::assert_snapshot!(expand_pretty(
+        quote!(catalogue, view_sql),
+        quote! {
+            #[derive(Debug)]
+            #[public_attr::derive(Clone)]
+            pub struct User {
+                pub name: String,
+                #[private]
+                pub password: String,
+            }
+        },
+    ));
+}
+
+#[test]
+fn tuple_struct_with_names() {
+    insta::assert_snapshot!(expand_pretty(
+        quote!(
+            public_name = "Visible",
+            crate = "crate::reexport",
+            catalogue
+        ),
+        quote! {
+            struct Pair<T>(T, #[private] #[phantom_attr::doc(hidden)] T);
+        },
+    ));
+}
+
+#[test]
+fn all_errors() {
+    insta::assert_debug_snapshot!(expand_errors(
+        quote!(privat_name = "Secret", seal = "yes"),
+        quote! {
+            #[publik_attr::derive(Debug)]
+            struct User {
+                #[public_attr::cfg(test)]
+                name: String,
+                #[private(erase_wth = "clear")]
+                password: String,
+            }
+        },
+    ));
+}
+
+#[test]
+fn column_without_sql_extensions() {
+    insta::assert_snapshot!(expand_pretty(
+        quote!(),
+        quote! {
+            struct Row {
+                #[column = "row_id"]
+                id: u32,
+                #[private]
+                #[column(name = "secret")]
+                token: String,
+            }
+        },
+    ));
+}